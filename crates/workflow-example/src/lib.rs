@@ -1,7 +1,7 @@
 use workflow::{http, log, rand, workflow, Result};
 
 #[workflow]
-fn workflow() -> Result<()> {
+fn workflow() -> Result<String> {
     // Get the location of the ISS
     let result = http::get("http://localhost:3000/iss/now", None)?
         .error_for_status()
@@ -26,7 +26,8 @@ fn workflow() -> Result<()> {
         .inspect_err(|err| {
             log::error!("could not update database: {:?}", err);
         })?;
-    log::debug!("database updated: {}", result.error_for_status()?.text()?);
+    let receipt = result.error_for_status()?.text()?;
+    log::debug!("database updated: {}", receipt);
 
-    Ok(())
+    Ok(receipt)
 }
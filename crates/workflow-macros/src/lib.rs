@@ -1,25 +1,67 @@
 use proc_macro::TokenStream;
 use quote::quote;
+use syn::spanned::Spanned;
 
 #[proc_macro_attribute]
-pub fn workflow(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn workflow(attr: TokenStream, item: TokenStream) -> TokenStream {
     let function = match syn::parse::<syn::ItemFn>(item.clone()) {
         Ok(item_fn) => item_fn,
         Err(err) => return token_stream_with_error(item, err),
     };
 
-    let block = function.block;
+    // The guest logger drops records above `log_level`, which defaults to `trace`.
+    let log_level = match log_level(attr) {
+        Ok(log_level) => log_level,
+        Err(err) => return token_stream_with_error(item, err),
+    };
+
+    // An optional single typed parameter is deserialized from the workflow input.
+    let input = match function.sig.inputs.len() {
+        0 => None,
+        1 => match function.sig.inputs.first() {
+            Some(syn::FnArg::Typed(pat_type)) => {
+                let ty = &pat_type.ty;
+                Some(quote! { workflow::input::<#ty>()? })
+            }
+            _ => {
+                return token_stream_with_error(
+                    item,
+                    syn::Error::new(
+                        function.sig.inputs.span(),
+                        "workflow functions cannot take a `self` parameter",
+                    ),
+                )
+            }
+        },
+        _ => {
+            return token_stream_with_error(
+                item,
+                syn::Error::new(
+                    function.sig.inputs.span(),
+                    "workflow functions take at most one input parameter",
+                ),
+            )
+        }
+    };
+
+    let ident = &function.sig.ident;
 
     quote! {
         pub use workflow::bindings::{self, Guest};
 
+        #function
+
         struct Component;
 
         impl Guest for Component {
-            fn execute() -> Result<()> {
-                workflow::logger::init(log::LevelFilter::Trace).unwrap();
+            fn execute() -> ::core::result::Result<Vec<u8>, bindings::WorkflowError> {
+                workflow::logger::init(log::LevelFilter::#log_level).unwrap();
 
-                #block
+                // The returned value is serialized as JSON and handed back to the host.
+                let output = #ident(#input)?;
+                workflow::serde_json::to_vec(&output).map_err(|err| {
+                    bindings::WorkflowError::new(None, format!("invalid output: {err}"))
+                })
             }
         }
 
@@ -28,6 +70,36 @@ pub fn workflow(_attr: TokenStream, item: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Parses the optional `log_level = "..."` argument into the name of a `LevelFilter` variant.
+fn log_level(attr: TokenStream) -> syn::Result<syn::Ident> {
+    let mut log_level = syn::Ident::new("Trace", proc_macro2::Span::call_site());
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("log_level") {
+            let value: syn::LitStr = meta.value()?.parse()?;
+            let variant = match value.value().to_lowercase().as_str() {
+                "off" => "Off",
+                "error" => "Error",
+                "warn" => "Warn",
+                "info" => "Info",
+                "debug" => "Debug",
+                "trace" => "Trace",
+                _ => {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        "expected one of `off`, `error`, `warn`, `info`, `debug` or `trace`",
+                    ))
+                }
+            };
+            log_level = syn::Ident::new(variant, value.span());
+            Ok(())
+        } else {
+            Err(meta.error("unsupported workflow argument"))
+        }
+    });
+    syn::parse::Parser::parse(parser, attr)?;
+    Ok(log_level)
+}
+
 fn token_stream_with_error(mut tokens: TokenStream, error: syn::Error) -> TokenStream {
     tokens.extend(TokenStream::from(error.into_compile_error()));
     tokens
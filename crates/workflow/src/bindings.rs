@@ -1,4 +1,4 @@
-// Generated by `wit-bindgen` 0.25.0. DO NOT EDIT!
+// Generated by `wit-bindgen` 0.30.0. DO NOT EDIT!
 // Options used:
 //   * pub-export-macro
 pub type GuestToHost = component::workflow::abi::GuestToHost;
@@ -9,103 +9,84 @@ pub fn call(input: &GuestToHost) -> HostToGuest {
     unsafe {
         let mut cleanup_list = _rt::Vec::new();
         #[repr(align(8))]
-        struct RetArea([::core::mem::MaybeUninit<u8>; 72]);
-        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 72]);
-        use component::workflow::abi::GuestToHost as V18;
-        let (
-            result19_0,
-            result19_1,
-            result19_2,
-            result19_3,
-            result19_4,
-            result19_5,
-            result19_6,
-            result19_7,
-            result19_8,
-        ) = match input {
-            V18::Input => (
-                0i32,
-                0i32,
-                ::core::ptr::null_mut(),
-                ::core::ptr::null_mut(),
-                0usize,
-                ::core::ptr::null_mut(),
-                0usize,
-                ::core::ptr::null_mut(),
-                0usize,
-            ),
-            V18::Random(e) => {
-                use component::workflow::abi::Types as V1;
-                let (result2_0, result2_1, result2_2) = match e {
-                    V1::BoolType(e) => (
-                        0i32,
-                        match e {
-                            true => 1,
-                            false => 0,
-                        } as *mut u8,
-                        0usize,
-                    ),
-                    V1::I32Type(e) => (1i32, _rt::as_i32(e) as *mut u8, 0usize),
-                    V1::F32Type(e) => (2i32, (_rt::as_f32(e)).to_bits() as i32 as *mut u8, 0usize),
+        struct RetArea([::core::mem::MaybeUninit<u8>; 96]);
+        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 96]);
+        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+        use component::workflow::abi::GuestToHost as V58;
+        match input {
+            V58::Input => {
+                *ptr0.add(0).cast::<u8>() = (0i32) as u8;
+            }
+            V58::Random(e) => {
+                *ptr0.add(0).cast::<u8>() = (1i32) as u8;
+                use component::workflow::abi::RandomType as V1;
+                match e {
+                    V1::BoolType => {
+                        *ptr0.add(8).cast::<u8>() = (0i32) as u8;
+                    }
+                    V1::I32Type => {
+                        *ptr0.add(8).cast::<u8>() = (1i32) as u8;
+                    }
+                    V1::F32Type => {
+                        *ptr0.add(8).cast::<u8>() = (2i32) as u8;
+                    }
                     V1::StringType(e) => {
-                        let vec0 = e;
-                        let ptr0 = vec0.as_ptr().cast::<u8>();
-                        let len0 = vec0.len();
-
-                        (3i32, ptr0.cast_mut(), len0)
+                        *ptr0.add(8).cast::<u8>() = (3i32) as u8;
+                        *ptr0.add(12).cast::<i32>() = _rt::as_i32(e);
                     }
-                };
-
-                (
-                    1i32,
-                    result2_0,
-                    result2_1,
-                    result2_2 as *mut u8,
-                    0usize,
-                    ::core::ptr::null_mut(),
-                    0usize,
-                    ::core::ptr::null_mut(),
-                    0usize,
-                )
-            }
-            V18::Time => (
-                2i32,
-                0i32,
-                ::core::ptr::null_mut(),
-                ::core::ptr::null_mut(),
-                0usize,
-                ::core::ptr::null_mut(),
-                0usize,
-                ::core::ptr::null_mut(),
-                0usize,
-            ),
-            V18::HttpRequest(e) => {
+                    V1::U64Type => {
+                        *ptr0.add(8).cast::<u8>() = (4i32) as u8;
+                    }
+                    V1::F64Type => {
+                        *ptr0.add(8).cast::<u8>() = (5i32) as u8;
+                    }
+                    V1::BytesType(e) => {
+                        *ptr0.add(8).cast::<u8>() = (6i32) as u8;
+                        *ptr0.add(12).cast::<i32>() = _rt::as_i32(e);
+                    }
+                }
+            }
+            V58::Time => {
+                *ptr0.add(0).cast::<u8>() = (2i32) as u8;
+            }
+            V58::HttpRequest(e) => {
+                *ptr0.add(0).cast::<u8>() = (3i32) as u8;
                 let component::workflow::http::Request {
-                    method: method3,
-                    body: body3,
-                    path: path3,
-                    headers: headers3,
+                    method: method2,
+                    body: body2,
+                    path: path2,
+                    headers: headers2,
+                    query: query2,
+                    timeout_ms: timeout_ms2,
+                    redirect: redirect2,
+                    max_response_size: max_response_size2,
                 } = e;
-                let (result5_0, result5_1, result5_2) = match body3 {
+                *ptr0.add(8).cast::<u8>() = (method2.clone() as i32) as u8;
+                match body2 {
                     Some(e) => {
-                        let vec4 = e;
-                        let ptr4 = vec4.as_ptr().cast::<u8>();
-                        let len4 = vec4.len();
-
-                        (1i32, ptr4.cast_mut(), len4)
+                        *ptr0.add(12).cast::<u8>() = (1i32) as u8;
+                        let vec3 = e;
+                        let ptr3 = vec3.as_ptr().cast::<u8>();
+                        let len3 = vec3.len();
+                        *ptr0.add(20).cast::<usize>() = len3;
+                        *ptr0.add(16).cast::<*mut u8>() = ptr3.cast_mut();
+                    }
+                    None => {
+                        *ptr0.add(12).cast::<u8>() = (0i32) as u8;
                     }
-                    None => (0i32, ::core::ptr::null_mut(), 0usize),
                 };
-                let vec6 = path3;
-                let ptr6 = vec6.as_ptr().cast::<u8>();
-                let len6 = vec6.len();
-                let vec10 = headers3;
-                let len10 = vec10.len();
-                let layout10 = _rt::alloc::Layout::from_size_align_unchecked(vec10.len() * 16, 4);
-                let result10 = if layout10.size() != 0 {
-                    let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
+                let vec4 = path2;
+                let ptr4 = vec4.as_ptr().cast::<u8>();
+                let len4 = vec4.len();
+                *ptr0.add(28).cast::<usize>() = len4;
+                *ptr0.add(24).cast::<*mut u8>() = ptr4.cast_mut();
+                let vec8 = headers2;
+                let len8 = vec8.len();
+                let layout8 = _rt::alloc::Layout::from_size_align_unchecked(vec8.len() * 16, 4);
+                let result8 = if layout8.size() != 0 {
+                    let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
                     if ptr.is_null() {
-                        _rt::alloc::handle_alloc_error(layout10);
+                        _rt::alloc::handle_alloc_error(layout8);
                     }
                     ptr
                 } else {
@@ -113,367 +94,775 @@ pub fn call(input: &GuestToHost) -> HostToGuest {
                         ::core::ptr::null_mut()
                     }
                 };
-                for (i, e) in vec10.into_iter().enumerate() {
-                    let base = result10.add(i * 16);
+                for (i, e) in vec8.into_iter().enumerate() {
+                    let base = result8.add(i * 16);
                     {
                         let component::workflow::http::Header {
-                            key: key7,
-                            value: value7,
+                            key: key5,
+                            value: value5,
                         } = e;
-                        let vec8 = key7;
-                        let ptr8 = vec8.as_ptr().cast::<u8>();
-                        let len8 = vec8.len();
-                        *base.add(4).cast::<usize>() = len8;
-                        *base.add(0).cast::<*mut u8>() = ptr8.cast_mut();
-                        let vec9 = value7;
-                        let ptr9 = vec9.as_ptr().cast::<u8>();
-                        let len9 = vec9.len();
-                        *base.add(12).cast::<usize>() = len9;
-                        *base.add(8).cast::<*mut u8>() = ptr9.cast_mut();
+                        let vec6 = key5;
+                        let ptr6 = vec6.as_ptr().cast::<u8>();
+                        let len6 = vec6.len();
+                        *base.add(4).cast::<usize>() = len6;
+                        *base.add(0).cast::<*mut u8>() = ptr6.cast_mut();
+                        let vec7 = value5;
+                        let ptr7 = vec7.as_ptr().cast::<u8>();
+                        let len7 = vec7.len();
+                        *base.add(12).cast::<usize>() = len7;
+                        *base.add(8).cast::<*mut u8>() = ptr7.cast_mut();
                     }
                 }
-                cleanup_list.extend_from_slice(&[(result10, layout10)]);
-
-                (
-                    3i32,
-                    method3.clone() as i32,
-                    result5_0 as *mut u8,
-                    result5_1,
-                    result5_2,
-                    ptr6.cast_mut(),
-                    len6,
-                    result10,
-                    len10,
-                )
-            }
-            V18::Log(e) => {
-                use component::workflow::abi::Level as V16;
-                let (result17_0, result17_1, result17_2) = match e {
-                    V16::Trace(e) => {
-                        let vec11 = e;
+                *ptr0.add(36).cast::<usize>() = len8;
+                *ptr0.add(32).cast::<*mut u8>() = result8;
+                let vec12 = query2;
+                let len12 = vec12.len();
+                let layout12 = _rt::alloc::Layout::from_size_align_unchecked(vec12.len() * 16, 4);
+                let result12 = if layout12.size() != 0 {
+                    let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                    if ptr.is_null() {
+                        _rt::alloc::handle_alloc_error(layout12);
+                    }
+                    ptr
+                } else {
+                    {
+                        ::core::ptr::null_mut()
+                    }
+                };
+                for (i, e) in vec12.into_iter().enumerate() {
+                    let base = result12.add(i * 16);
+                    {
+                        let component::workflow::http::Header {
+                            key: key9,
+                            value: value9,
+                        } = e;
+                        let vec10 = key9;
+                        let ptr10 = vec10.as_ptr().cast::<u8>();
+                        let len10 = vec10.len();
+                        *base.add(4).cast::<usize>() = len10;
+                        *base.add(0).cast::<*mut u8>() = ptr10.cast_mut();
+                        let vec11 = value9;
                         let ptr11 = vec11.as_ptr().cast::<u8>();
                         let len11 = vec11.len();
-
-                        (0i32, ptr11.cast_mut(), len11)
+                        *base.add(12).cast::<usize>() = len11;
+                        *base.add(8).cast::<*mut u8>() = ptr11.cast_mut();
+                    }
+                }
+                *ptr0.add(44).cast::<usize>() = len12;
+                *ptr0.add(40).cast::<*mut u8>() = result12;
+                match timeout_ms2 {
+                    Some(e) => {
+                        *ptr0.add(48).cast::<u8>() = (1i32) as u8;
+                        *ptr0.add(56).cast::<i64>() = _rt::as_i64(e);
+                    }
+                    None => {
+                        *ptr0.add(48).cast::<u8>() = (0i32) as u8;
+                    }
+                };
+                match redirect2 {
+                    Some(e) => {
+                        *ptr0.add(64).cast::<u8>() = (1i32) as u8;
+                        use component::workflow::http::RedirectPolicy as V13;
+                        match e {
+                            V13::None => {
+                                *ptr0.add(68).cast::<u8>() = (0i32) as u8;
+                            }
+                            V13::Limited(e) => {
+                                *ptr0.add(68).cast::<u8>() = (1i32) as u8;
+                                *ptr0.add(72).cast::<i32>() = _rt::as_i32(e);
+                            }
+                        }
                     }
-                    V16::Debug(e) => {
-                        let vec12 = e;
-                        let ptr12 = vec12.as_ptr().cast::<u8>();
-                        let len12 = vec12.len();
-
-                        (1i32, ptr12.cast_mut(), len12)
+                    None => {
+                        *ptr0.add(64).cast::<u8>() = (0i32) as u8;
                     }
-                    V16::Info(e) => {
-                        let vec13 = e;
-                        let ptr13 = vec13.as_ptr().cast::<u8>();
-                        let len13 = vec13.len();
-
-                        (2i32, ptr13.cast_mut(), len13)
+                };
+                match max_response_size2 {
+                    Some(e) => {
+                        *ptr0.add(80).cast::<u8>() = (1i32) as u8;
+                        *ptr0.add(88).cast::<i64>() = _rt::as_i64(e);
                     }
-                    V16::Warn(e) => {
-                        let vec14 = e;
-                        let ptr14 = vec14.as_ptr().cast::<u8>();
-                        let len14 = vec14.len();
-
-                        (3i32, ptr14.cast_mut(), len14)
+                    None => {
+                        *ptr0.add(80).cast::<u8>() = (0i32) as u8;
                     }
-                    V16::Error(e) => {
-                        let vec15 = e;
+                };
+                cleanup_list.extend_from_slice(&[(result8, layout8), (result12, layout12)]);
+            }
+            V58::Log(e) => {
+                *ptr0.add(0).cast::<u8>() = (4i32) as u8;
+                use component::workflow::abi::Level as V49;
+                match e {
+                    V49::Trace(e) => {
+                        *ptr0.add(8).cast::<u8>() = (0i32) as u8;
+                        let component::workflow::abi::LogRecord {
+                            message: message14,
+                            target: target14,
+                            fields: fields14,
+                        } = e;
+                        let vec15 = message14;
                         let ptr15 = vec15.as_ptr().cast::<u8>();
                         let len15 = vec15.len();
-
-                        (4i32, ptr15.cast_mut(), len15)
+                        *ptr0.add(16).cast::<usize>() = len15;
+                        *ptr0.add(12).cast::<*mut u8>() = ptr15.cast_mut();
+                        match target14 {
+                            Some(e) => {
+                                *ptr0.add(20).cast::<u8>() = (1i32) as u8;
+                                let vec16 = e;
+                                let ptr16 = vec16.as_ptr().cast::<u8>();
+                                let len16 = vec16.len();
+                                *ptr0.add(28).cast::<usize>() = len16;
+                                *ptr0.add(24).cast::<*mut u8>() = ptr16.cast_mut();
+                            }
+                            None => {
+                                *ptr0.add(20).cast::<u8>() = (0i32) as u8;
+                            }
+                        };
+                        let vec20 = fields14;
+                        let len20 = vec20.len();
+                        let layout20 =
+                            _rt::alloc::Layout::from_size_align_unchecked(vec20.len() * 16, 4);
+                        let result20 = if layout20.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout20).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout20);
+                            }
+                            ptr
+                        } else {
+                            {
+                                ::core::ptr::null_mut()
+                            }
+                        };
+                        for (i, e) in vec20.into_iter().enumerate() {
+                            let base = result20.add(i * 16);
+                            {
+                                let component::workflow::abi::Field {
+                                    key: key17,
+                                    value: value17,
+                                } = e;
+                                let vec18 = key17;
+                                let ptr18 = vec18.as_ptr().cast::<u8>();
+                                let len18 = vec18.len();
+                                *base.add(4).cast::<usize>() = len18;
+                                *base.add(0).cast::<*mut u8>() = ptr18.cast_mut();
+                                let vec19 = value17;
+                                let ptr19 = vec19.as_ptr().cast::<u8>();
+                                let len19 = vec19.len();
+                                *base.add(12).cast::<usize>() = len19;
+                                *base.add(8).cast::<*mut u8>() = ptr19.cast_mut();
+                            }
+                        }
+                        *ptr0.add(36).cast::<usize>() = len20;
+                        *ptr0.add(32).cast::<*mut u8>() = result20;
+                        cleanup_list.extend_from_slice(&[(result20, layout20)]);
                     }
-                };
-
-                (
-                    4i32,
-                    result17_0,
-                    result17_1,
-                    result17_2 as *mut u8,
-                    0usize,
-                    ::core::ptr::null_mut(),
-                    0usize,
-                    ::core::ptr::null_mut(),
-                    0usize,
-                )
+                    V49::Debug(e) => {
+                        *ptr0.add(8).cast::<u8>() = (1i32) as u8;
+                        let component::workflow::abi::LogRecord {
+                            message: message21,
+                            target: target21,
+                            fields: fields21,
+                        } = e;
+                        let vec22 = message21;
+                        let ptr22 = vec22.as_ptr().cast::<u8>();
+                        let len22 = vec22.len();
+                        *ptr0.add(16).cast::<usize>() = len22;
+                        *ptr0.add(12).cast::<*mut u8>() = ptr22.cast_mut();
+                        match target21 {
+                            Some(e) => {
+                                *ptr0.add(20).cast::<u8>() = (1i32) as u8;
+                                let vec23 = e;
+                                let ptr23 = vec23.as_ptr().cast::<u8>();
+                                let len23 = vec23.len();
+                                *ptr0.add(28).cast::<usize>() = len23;
+                                *ptr0.add(24).cast::<*mut u8>() = ptr23.cast_mut();
+                            }
+                            None => {
+                                *ptr0.add(20).cast::<u8>() = (0i32) as u8;
+                            }
+                        };
+                        let vec27 = fields21;
+                        let len27 = vec27.len();
+                        let layout27 =
+                            _rt::alloc::Layout::from_size_align_unchecked(vec27.len() * 16, 4);
+                        let result27 = if layout27.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout27).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout27);
+                            }
+                            ptr
+                        } else {
+                            {
+                                ::core::ptr::null_mut()
+                            }
+                        };
+                        for (i, e) in vec27.into_iter().enumerate() {
+                            let base = result27.add(i * 16);
+                            {
+                                let component::workflow::abi::Field {
+                                    key: key24,
+                                    value: value24,
+                                } = e;
+                                let vec25 = key24;
+                                let ptr25 = vec25.as_ptr().cast::<u8>();
+                                let len25 = vec25.len();
+                                *base.add(4).cast::<usize>() = len25;
+                                *base.add(0).cast::<*mut u8>() = ptr25.cast_mut();
+                                let vec26 = value24;
+                                let ptr26 = vec26.as_ptr().cast::<u8>();
+                                let len26 = vec26.len();
+                                *base.add(12).cast::<usize>() = len26;
+                                *base.add(8).cast::<*mut u8>() = ptr26.cast_mut();
+                            }
+                        }
+                        *ptr0.add(36).cast::<usize>() = len27;
+                        *ptr0.add(32).cast::<*mut u8>() = result27;
+                        cleanup_list.extend_from_slice(&[(result27, layout27)]);
+                    }
+                    V49::Info(e) => {
+                        *ptr0.add(8).cast::<u8>() = (2i32) as u8;
+                        let component::workflow::abi::LogRecord {
+                            message: message28,
+                            target: target28,
+                            fields: fields28,
+                        } = e;
+                        let vec29 = message28;
+                        let ptr29 = vec29.as_ptr().cast::<u8>();
+                        let len29 = vec29.len();
+                        *ptr0.add(16).cast::<usize>() = len29;
+                        *ptr0.add(12).cast::<*mut u8>() = ptr29.cast_mut();
+                        match target28 {
+                            Some(e) => {
+                                *ptr0.add(20).cast::<u8>() = (1i32) as u8;
+                                let vec30 = e;
+                                let ptr30 = vec30.as_ptr().cast::<u8>();
+                                let len30 = vec30.len();
+                                *ptr0.add(28).cast::<usize>() = len30;
+                                *ptr0.add(24).cast::<*mut u8>() = ptr30.cast_mut();
+                            }
+                            None => {
+                                *ptr0.add(20).cast::<u8>() = (0i32) as u8;
+                            }
+                        };
+                        let vec34 = fields28;
+                        let len34 = vec34.len();
+                        let layout34 =
+                            _rt::alloc::Layout::from_size_align_unchecked(vec34.len() * 16, 4);
+                        let result34 = if layout34.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout34).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout34);
+                            }
+                            ptr
+                        } else {
+                            {
+                                ::core::ptr::null_mut()
+                            }
+                        };
+                        for (i, e) in vec34.into_iter().enumerate() {
+                            let base = result34.add(i * 16);
+                            {
+                                let component::workflow::abi::Field {
+                                    key: key31,
+                                    value: value31,
+                                } = e;
+                                let vec32 = key31;
+                                let ptr32 = vec32.as_ptr().cast::<u8>();
+                                let len32 = vec32.len();
+                                *base.add(4).cast::<usize>() = len32;
+                                *base.add(0).cast::<*mut u8>() = ptr32.cast_mut();
+                                let vec33 = value31;
+                                let ptr33 = vec33.as_ptr().cast::<u8>();
+                                let len33 = vec33.len();
+                                *base.add(12).cast::<usize>() = len33;
+                                *base.add(8).cast::<*mut u8>() = ptr33.cast_mut();
+                            }
+                        }
+                        *ptr0.add(36).cast::<usize>() = len34;
+                        *ptr0.add(32).cast::<*mut u8>() = result34;
+                        cleanup_list.extend_from_slice(&[(result34, layout34)]);
+                    }
+                    V49::Warn(e) => {
+                        *ptr0.add(8).cast::<u8>() = (3i32) as u8;
+                        let component::workflow::abi::LogRecord {
+                            message: message35,
+                            target: target35,
+                            fields: fields35,
+                        } = e;
+                        let vec36 = message35;
+                        let ptr36 = vec36.as_ptr().cast::<u8>();
+                        let len36 = vec36.len();
+                        *ptr0.add(16).cast::<usize>() = len36;
+                        *ptr0.add(12).cast::<*mut u8>() = ptr36.cast_mut();
+                        match target35 {
+                            Some(e) => {
+                                *ptr0.add(20).cast::<u8>() = (1i32) as u8;
+                                let vec37 = e;
+                                let ptr37 = vec37.as_ptr().cast::<u8>();
+                                let len37 = vec37.len();
+                                *ptr0.add(28).cast::<usize>() = len37;
+                                *ptr0.add(24).cast::<*mut u8>() = ptr37.cast_mut();
+                            }
+                            None => {
+                                *ptr0.add(20).cast::<u8>() = (0i32) as u8;
+                            }
+                        };
+                        let vec41 = fields35;
+                        let len41 = vec41.len();
+                        let layout41 =
+                            _rt::alloc::Layout::from_size_align_unchecked(vec41.len() * 16, 4);
+                        let result41 = if layout41.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout41).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout41);
+                            }
+                            ptr
+                        } else {
+                            {
+                                ::core::ptr::null_mut()
+                            }
+                        };
+                        for (i, e) in vec41.into_iter().enumerate() {
+                            let base = result41.add(i * 16);
+                            {
+                                let component::workflow::abi::Field {
+                                    key: key38,
+                                    value: value38,
+                                } = e;
+                                let vec39 = key38;
+                                let ptr39 = vec39.as_ptr().cast::<u8>();
+                                let len39 = vec39.len();
+                                *base.add(4).cast::<usize>() = len39;
+                                *base.add(0).cast::<*mut u8>() = ptr39.cast_mut();
+                                let vec40 = value38;
+                                let ptr40 = vec40.as_ptr().cast::<u8>();
+                                let len40 = vec40.len();
+                                *base.add(12).cast::<usize>() = len40;
+                                *base.add(8).cast::<*mut u8>() = ptr40.cast_mut();
+                            }
+                        }
+                        *ptr0.add(36).cast::<usize>() = len41;
+                        *ptr0.add(32).cast::<*mut u8>() = result41;
+                        cleanup_list.extend_from_slice(&[(result41, layout41)]);
+                    }
+                    V49::Error(e) => {
+                        *ptr0.add(8).cast::<u8>() = (4i32) as u8;
+                        let component::workflow::abi::LogRecord {
+                            message: message42,
+                            target: target42,
+                            fields: fields42,
+                        } = e;
+                        let vec43 = message42;
+                        let ptr43 = vec43.as_ptr().cast::<u8>();
+                        let len43 = vec43.len();
+                        *ptr0.add(16).cast::<usize>() = len43;
+                        *ptr0.add(12).cast::<*mut u8>() = ptr43.cast_mut();
+                        match target42 {
+                            Some(e) => {
+                                *ptr0.add(20).cast::<u8>() = (1i32) as u8;
+                                let vec44 = e;
+                                let ptr44 = vec44.as_ptr().cast::<u8>();
+                                let len44 = vec44.len();
+                                *ptr0.add(28).cast::<usize>() = len44;
+                                *ptr0.add(24).cast::<*mut u8>() = ptr44.cast_mut();
+                            }
+                            None => {
+                                *ptr0.add(20).cast::<u8>() = (0i32) as u8;
+                            }
+                        };
+                        let vec48 = fields42;
+                        let len48 = vec48.len();
+                        let layout48 =
+                            _rt::alloc::Layout::from_size_align_unchecked(vec48.len() * 16, 4);
+                        let result48 = if layout48.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout48).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout48);
+                            }
+                            ptr
+                        } else {
+                            {
+                                ::core::ptr::null_mut()
+                            }
+                        };
+                        for (i, e) in vec48.into_iter().enumerate() {
+                            let base = result48.add(i * 16);
+                            {
+                                let component::workflow::abi::Field {
+                                    key: key45,
+                                    value: value45,
+                                } = e;
+                                let vec46 = key45;
+                                let ptr46 = vec46.as_ptr().cast::<u8>();
+                                let len46 = vec46.len();
+                                *base.add(4).cast::<usize>() = len46;
+                                *base.add(0).cast::<*mut u8>() = ptr46.cast_mut();
+                                let vec47 = value45;
+                                let ptr47 = vec47.as_ptr().cast::<u8>();
+                                let len47 = vec47.len();
+                                *base.add(12).cast::<usize>() = len47;
+                                *base.add(8).cast::<*mut u8>() = ptr47.cast_mut();
+                            }
+                        }
+                        *ptr0.add(36).cast::<usize>() = len48;
+                        *ptr0.add(32).cast::<*mut u8>() = result48;
+                        cleanup_list.extend_from_slice(&[(result48, layout48)]);
+                    }
+                }
             }
-        };
-        let ptr20 = ret_area.0.as_mut_ptr().cast::<u8>();
+            V58::Sleep(e) => {
+                *ptr0.add(0).cast::<u8>() = (5i32) as u8;
+                let component::workflow::abi::Duration {
+                    secs: secs50,
+                    nanos: nanos50,
+                } = e;
+                *ptr0.add(8).cast::<i64>() = _rt::as_i64(secs50);
+                *ptr0.add(16).cast::<i32>() = _rt::as_i32(nanos50);
+            }
+            V58::SleepUntil(e) => {
+                *ptr0.add(0).cast::<u8>() = (6i32) as u8;
+                let component::workflow::abi::SystemTime {
+                    tv_sec: tv_sec51,
+                    tv_nsec: tv_nsec51,
+                } = e;
+                *ptr0.add(8).cast::<i64>() = _rt::as_i64(tv_sec51);
+                *ptr0.add(16).cast::<i32>() = _rt::as_i32(tv_nsec51);
+            }
+            V58::WaitForSignal(e) => {
+                *ptr0.add(0).cast::<u8>() = (7i32) as u8;
+                let vec52 = e;
+                let ptr52 = vec52.as_ptr().cast::<u8>();
+                let len52 = vec52.len();
+                *ptr0.add(12).cast::<usize>() = len52;
+                *ptr0.add(8).cast::<*mut u8>() = ptr52.cast_mut();
+            }
+            V58::StartChild(e) => {
+                *ptr0.add(0).cast::<u8>() = (8i32) as u8;
+                let component::workflow::abi::ChildWorkflow {
+                    component_name: component_name53,
+                    input: input53,
+                } = e;
+                let vec54 = component_name53;
+                let ptr54 = vec54.as_ptr().cast::<u8>();
+                let len54 = vec54.len();
+                *ptr0.add(12).cast::<usize>() = len54;
+                *ptr0.add(8).cast::<*mut u8>() = ptr54.cast_mut();
+                let vec55 = input53;
+                let ptr55 = vec55.as_ptr().cast::<u8>();
+                let len55 = vec55.len();
+                *ptr0.add(20).cast::<usize>() = len55;
+                *ptr0.add(16).cast::<*mut u8>() = ptr55.cast_mut();
+            }
+            V58::AwaitChild(e) => {
+                *ptr0.add(0).cast::<u8>() = (9i32) as u8;
+                let vec56 = e;
+                let ptr56 = vec56.as_ptr().cast::<u8>();
+                let len56 = vec56.len();
+                *ptr0.add(12).cast::<usize>() = len56;
+                *ptr0.add(8).cast::<*mut u8>() = ptr56.cast_mut();
+            }
+            V58::ContinueAsNew(e) => {
+                *ptr0.add(0).cast::<u8>() = (10i32) as u8;
+                let vec57 = e;
+                let ptr57 = vec57.as_ptr().cast::<u8>();
+                let len57 = vec57.len();
+                *ptr0.add(12).cast::<usize>() = len57;
+                *ptr0.add(8).cast::<*mut u8>() = ptr57.cast_mut();
+            }
+        }
+        let ptr59 = ret_area.0.as_mut_ptr().cast::<u8>();
         #[cfg(target_arch = "wasm32")]
         #[link(wasm_import_module = "$root")]
         extern "C" {
             #[link_name = "call"]
-            fn wit_import(
-                _: i32,
-                _: i32,
-                _: *mut u8,
-                _: *mut u8,
-                _: usize,
-                _: *mut u8,
-                _: usize,
-                _: *mut u8,
-                _: usize,
-                _: *mut u8,
-            );
+            fn wit_import(_: *mut u8, _: *mut u8);
         }
-
         #[cfg(not(target_arch = "wasm32"))]
-        fn wit_import(
-            _: i32,
-            _: i32,
-            _: *mut u8,
-            _: *mut u8,
-            _: usize,
-            _: *mut u8,
-            _: usize,
-            _: *mut u8,
-            _: usize,
-            _: *mut u8,
-        ) {
+        fn wit_import(_: *mut u8, _: *mut u8) {
             unreachable!()
         }
-        wit_import(
-            result19_0, result19_1, result19_2, result19_3, result19_4, result19_5, result19_6,
-            result19_7, result19_8, ptr20,
-        );
-        let l21 = *ptr20.add(0).cast::<*mut u8>();
-        let l22 = *ptr20.add(4).cast::<usize>();
-        let len23 = l22;
-        let bytes23 = _rt::Vec::from_raw_parts(l21.cast(), len23, len23);
-        let l24 = i32::from(*ptr20.add(8).cast::<u8>());
-        use component::workflow::abi::Content as V72;
-        let v72 = match l24 {
-            0 => V72::Unit,
+        wit_import(ptr0, ptr59);
+        let l60 = *ptr59.add(0).cast::<*mut u8>();
+        let l61 = *ptr59.add(4).cast::<usize>();
+        let len62 = l61;
+        let bytes62 = _rt::Vec::from_raw_parts(l60.cast(), len62, len62);
+        let l63 = i32::from(*ptr59.add(8).cast::<u8>());
+        use component::workflow::abi::Content as V142;
+        let v142 = match l63 {
+            0 => V142::Unit,
             1 => {
-                let e72 = {
-                    let l25 = i32::from(*ptr20.add(16).cast::<u8>());
-                    use component::workflow::abi::Types as V32;
-                    let v32 = match l25 {
+                let e142 = {
+                    let l64 = i32::from(*ptr59.add(16).cast::<u8>());
+                    use component::workflow::abi::Types as V76;
+                    let v76 = match l64 {
                         0 => {
-                            let e32 = {
-                                let l26 = i32::from(*ptr20.add(20).cast::<u8>());
-
-                                _rt::bool_lift(l26 as u8)
+                            let e76 = {
+                                let l65 = i32::from(*ptr59.add(24).cast::<u8>());
+                                _rt::bool_lift(l65 as u8)
                             };
-                            V32::BoolType(e32)
+                            V76::BoolType(e76)
                         }
                         1 => {
-                            let e32 = {
-                                let l27 = *ptr20.add(20).cast::<i32>();
-
-                                l27 as u32
+                            let e76 = {
+                                let l66 = *ptr59.add(24).cast::<i32>();
+                                l66 as u32
                             };
-                            V32::I32Type(e32)
+                            V76::I32Type(e76)
                         }
                         2 => {
-                            let e32 = {
-                                let l28 = *ptr20.add(20).cast::<f32>();
-
-                                l28
+                            let e76 = {
+                                let l67 = *ptr59.add(24).cast::<f32>();
+                                l67
                             };
-                            V32::F32Type(e32)
+                            V76::F32Type(e76)
+                        }
+                        3 => {
+                            let e76 = {
+                                let l68 = *ptr59.add(24).cast::<*mut u8>();
+                                let l69 = *ptr59.add(28).cast::<usize>();
+                                let len70 = l69;
+                                let bytes70 = _rt::Vec::from_raw_parts(l68.cast(), len70, len70);
+                                _rt::string_lift(bytes70)
+                            };
+                            V76::StringType(e76)
+                        }
+                        4 => {
+                            let e76 = {
+                                let l71 = *ptr59.add(24).cast::<i64>();
+                                l71 as u64
+                            };
+                            V76::U64Type(e76)
+                        }
+                        5 => {
+                            let e76 = {
+                                let l72 = *ptr59.add(24).cast::<f64>();
+                                l72
+                            };
+                            V76::F64Type(e76)
                         }
                         n => {
-                            debug_assert_eq!(n, 3, "invalid enum discriminant");
-                            let e32 = {
-                                let l29 = *ptr20.add(20).cast::<*mut u8>();
-                                let l30 = *ptr20.add(24).cast::<usize>();
-                                let len31 = l30;
-                                let bytes31 = _rt::Vec::from_raw_parts(l29.cast(), len31, len31);
-
-                                _rt::string_lift(bytes31)
+                            debug_assert_eq!(n, 6, "invalid enum discriminant");
+                            let e76 = {
+                                let l73 = *ptr59.add(24).cast::<*mut u8>();
+                                let l74 = *ptr59.add(28).cast::<usize>();
+                                let len75 = l74;
+                                _rt::Vec::from_raw_parts(l73.cast(), len75, len75)
                             };
-                            V32::StringType(e32)
+                            V76::BytesType(e76)
                         }
                     };
-
-                    v32
+                    v76
                 };
-                V72::Random(e72)
+                V142::Random(e142)
             }
             2 => {
-                let e72 = {
-                    let l33 = i32::from(*ptr20.add(16).cast::<u8>());
-                    use component::workflow::abi::Types as V40;
-                    let v40 = match l33 {
+                let e142 = {
+                    let l77 = i32::from(*ptr59.add(16).cast::<u8>());
+                    use component::workflow::abi::Types as V89;
+                    let v89 = match l77 {
                         0 => {
-                            let e40 = {
-                                let l34 = i32::from(*ptr20.add(20).cast::<u8>());
-
-                                _rt::bool_lift(l34 as u8)
+                            let e89 = {
+                                let l78 = i32::from(*ptr59.add(24).cast::<u8>());
+                                _rt::bool_lift(l78 as u8)
                             };
-                            V40::BoolType(e40)
+                            V89::BoolType(e89)
                         }
                         1 => {
-                            let e40 = {
-                                let l35 = *ptr20.add(20).cast::<i32>();
-
-                                l35 as u32
+                            let e89 = {
+                                let l79 = *ptr59.add(24).cast::<i32>();
+                                l79 as u32
                             };
-                            V40::I32Type(e40)
+                            V89::I32Type(e89)
                         }
                         2 => {
-                            let e40 = {
-                                let l36 = *ptr20.add(20).cast::<f32>();
-
-                                l36
+                            let e89 = {
+                                let l80 = *ptr59.add(24).cast::<f32>();
+                                l80
+                            };
+                            V89::F32Type(e89)
+                        }
+                        3 => {
+                            let e89 = {
+                                let l81 = *ptr59.add(24).cast::<*mut u8>();
+                                let l82 = *ptr59.add(28).cast::<usize>();
+                                let len83 = l82;
+                                let bytes83 = _rt::Vec::from_raw_parts(l81.cast(), len83, len83);
+                                _rt::string_lift(bytes83)
                             };
-                            V40::F32Type(e40)
+                            V89::StringType(e89)
+                        }
+                        4 => {
+                            let e89 = {
+                                let l84 = *ptr59.add(24).cast::<i64>();
+                                l84 as u64
+                            };
+                            V89::U64Type(e89)
+                        }
+                        5 => {
+                            let e89 = {
+                                let l85 = *ptr59.add(24).cast::<f64>();
+                                l85
+                            };
+                            V89::F64Type(e89)
                         }
                         n => {
-                            debug_assert_eq!(n, 3, "invalid enum discriminant");
-                            let e40 = {
-                                let l37 = *ptr20.add(20).cast::<*mut u8>();
-                                let l38 = *ptr20.add(24).cast::<usize>();
-                                let len39 = l38;
-                                let bytes39 = _rt::Vec::from_raw_parts(l37.cast(), len39, len39);
-
-                                _rt::string_lift(bytes39)
+                            debug_assert_eq!(n, 6, "invalid enum discriminant");
+                            let e89 = {
+                                let l86 = *ptr59.add(24).cast::<*mut u8>();
+                                let l87 = *ptr59.add(28).cast::<usize>();
+                                let len88 = l87;
+                                _rt::Vec::from_raw_parts(l86.cast(), len88, len88)
                             };
-                            V40::StringType(e40)
+                            V89::BytesType(e89)
                         }
                     };
-
-                    v40
+                    v89
                 };
-                V72::Value(e72)
+                V142::Value(e142)
             }
             3 => {
-                let e72 = {
-                    let l41 = i32::from(*ptr20.add(16).cast::<u8>());
-
-                    match l41 {
+                let e142 = {
+                    let l90 = i32::from(*ptr59.add(16).cast::<u8>());
+                    match l90 {
                         0 => {
                             let e = {
-                                let l42 = i32::from(*ptr20.add(24).cast::<u16>());
-                                let l43 = i32::from(*ptr20.add(26).cast::<u8>());
-                                use component::workflow::http::Version as V44;
-                                let v44 = match l43 {
-                                    0 => V44::HttpZeroNine,
-                                    1 => V44::HttpOneZero,
-                                    2 => V44::HttpOneOne,
-                                    3 => V44::HttpTwoZero,
+                                let l91 = i32::from(*ptr59.add(24).cast::<u16>());
+                                let l92 = i32::from(*ptr59.add(26).cast::<u8>());
+                                use component::workflow::http::Version as V93;
+                                let v93 = match l92 {
+                                    0 => V93::HttpZeroNine,
+                                    1 => V93::HttpOneZero,
+                                    2 => V93::HttpOneOne,
+                                    3 => V93::HttpTwoZero,
                                     n => {
                                         debug_assert_eq!(n, 4, "invalid enum discriminant");
-                                        V44::HttpThreeZero
+                                        V93::HttpThreeZero
                                     }
                                 };
-                                let l45 = *ptr20.add(28).cast::<*mut u8>();
-                                let l46 = *ptr20.add(32).cast::<usize>();
-                                let base53 = l45;
-                                let len53 = l46;
-                                let mut result53 = _rt::Vec::with_capacity(len53);
-                                for i in 0..len53 {
-                                    let base = base53.add(i * 16);
-                                    let e53 = {
-                                        let l47 = *base.add(0).cast::<*mut u8>();
-                                        let l48 = *base.add(4).cast::<usize>();
-                                        let len49 = l48;
-                                        let bytes49 =
-                                            _rt::Vec::from_raw_parts(l47.cast(), len49, len49);
-                                        let l50 = *base.add(8).cast::<*mut u8>();
-                                        let l51 = *base.add(12).cast::<usize>();
-                                        let len52 = l51;
-                                        let bytes52 =
-                                            _rt::Vec::from_raw_parts(l50.cast(), len52, len52);
-
+                                let l94 = *ptr59.add(28).cast::<*mut u8>();
+                                let l95 = *ptr59.add(32).cast::<usize>();
+                                let base102 = l94;
+                                let len102 = l95;
+                                let mut result102 = _rt::Vec::with_capacity(len102);
+                                for i in 0..len102 {
+                                    let base = base102.add(i * 16);
+                                    let e102 = {
+                                        let l96 = *base.add(0).cast::<*mut u8>();
+                                        let l97 = *base.add(4).cast::<usize>();
+                                        let len98 = l97;
+                                        let bytes98 =
+                                            _rt::Vec::from_raw_parts(l96.cast(), len98, len98);
+                                        let l99 = *base.add(8).cast::<*mut u8>();
+                                        let l100 = *base.add(12).cast::<usize>();
+                                        let len101 = l100;
+                                        let bytes101 =
+                                            _rt::Vec::from_raw_parts(l99.cast(), len101, len101);
                                         component::workflow::http::Header {
-                                            key: _rt::string_lift(bytes49),
-                                            value: _rt::string_lift(bytes52),
+                                            key: _rt::string_lift(bytes98),
+                                            value: _rt::string_lift(bytes101),
                                         }
                                     };
-                                    result53.push(e53);
+                                    result102.push(e102);
                                 }
-                                _rt::cabi_dealloc(base53, len53 * 16, 4);
-                                let l54 = i32::from(*ptr20.add(40).cast::<u8>());
-                                let l56 = *ptr20.add(56).cast::<*mut u8>();
-                                let l57 = *ptr20.add(60).cast::<usize>();
-                                let len58 = l57;
-                                let bytes58 = _rt::Vec::from_raw_parts(l56.cast(), len58, len58);
-                                let l59 = *ptr20.add(64).cast::<*mut u8>();
-                                let l60 = *ptr20.add(68).cast::<usize>();
-                                let len61 = l60;
-
+                                _rt::cabi_dealloc(base102, len102 * 16, 4);
+                                let l103 = i32::from(*ptr59.add(40).cast::<u8>());
+                                let l105 = *ptr59.add(56).cast::<*mut u8>();
+                                let l106 = *ptr59.add(60).cast::<usize>();
+                                let len107 = l106;
+                                let bytes107 =
+                                    _rt::Vec::from_raw_parts(l105.cast(), len107, len107);
+                                let l108 = *ptr59.add(64).cast::<*mut u8>();
+                                let l109 = *ptr59.add(68).cast::<usize>();
+                                let len110 = l109;
                                 component::workflow::http::Response {
-                                    status: l42 as u16,
-                                    http_version: v44,
-                                    headers: result53,
-                                    content_length: match l54 {
+                                    status: l91 as u16,
+                                    http_version: v93,
+                                    headers: result102,
+                                    content_length: match l103 {
                                         0 => None,
                                         1 => {
                                             let e = {
-                                                let l55 = *ptr20.add(48).cast::<i64>();
-
-                                                l55 as u64
+                                                let l104 = *ptr59.add(48).cast::<i64>();
+                                                l104 as u64
                                             };
                                             Some(e)
                                         }
                                         _ => _rt::invalid_enum_discriminant(),
                                     },
-                                    url: _rt::string_lift(bytes58),
-                                    body: _rt::Vec::from_raw_parts(l59.cast(), len61, len61),
+                                    url: _rt::string_lift(bytes107),
+                                    body: _rt::Vec::from_raw_parts(l108.cast(), len110, len110),
                                 }
                             };
                             Ok(e)
                         }
                         1 => {
                             let e = {
-                                let l62 = i32::from(*ptr20.add(24).cast::<u8>());
-                                let l66 = i32::from(*ptr20.add(36).cast::<u8>());
-
+                                let l111 = i32::from(*ptr59.add(24).cast::<u8>());
+                                let l115 = i32::from(*ptr59.add(36).cast::<u8>());
                                 component::workflow::http::Error {
-                                    url: match l62 {
+                                    url: match l111 {
                                         0 => None,
                                         1 => {
                                             let e = {
-                                                let l63 = *ptr20.add(28).cast::<*mut u8>();
-                                                let l64 = *ptr20.add(32).cast::<usize>();
-                                                let len65 = l64;
-                                                let bytes65 = _rt::Vec::from_raw_parts(
-                                                    l63.cast(),
-                                                    len65,
-                                                    len65,
+                                                let l112 = *ptr59.add(28).cast::<*mut u8>();
+                                                let l113 = *ptr59.add(32).cast::<usize>();
+                                                let len114 = l113;
+                                                let bytes114 = _rt::Vec::from_raw_parts(
+                                                    l112.cast(),
+                                                    len114,
+                                                    len114,
                                                 );
-
-                                                _rt::string_lift(bytes65)
+                                                _rt::string_lift(bytes114)
                                             };
                                             Some(e)
                                         }
                                         _ => _rt::invalid_enum_discriminant(),
                                     },
-                                    kind: match l66 {
+                                    kind: match l115 {
                                         0 => None,
                                         1 => {
                                             let e = {
-                                                let l67 = i32::from(*ptr20.add(38).cast::<u8>());
-                                                use component::workflow::http::Kind as V69;
-                                                let v69 = match l67 {
-                                                    0 => V69::Builder,
-                                                    1 => V69::Request,
-                                                    2 => V69::Redirect,
+                                                let l116 = i32::from(*ptr59.add(40).cast::<u8>());
+                                                use component::workflow::http::Kind as V121;
+                                                let v121 = match l116 {
+                                                    0 => V121::Builder,
+                                                    1 => V121::Request,
+                                                    2 => V121::Redirect,
                                                     3 => {
-                                                        let e69 = {
-                                                            let l68 = i32::from(
-                                                                *ptr20.add(40).cast::<u16>(),
+                                                        let e121 = {
+                                                            let l117 = i32::from(
+                                                                *ptr59.add(44).cast::<u16>(),
                                                             );
-
-                                                            l68 as u16
+                                                            l117 as u16
                                                         };
-                                                        V69::Status(e69)
+                                                        V121::Status(e121)
                                                     }
-                                                    4 => V69::Body,
-                                                    5 => V69::Decode,
+                                                    4 => V121::Body,
+                                                    5 => V121::Decode,
+                                                    6 => V121::Upgrade,
+                                                    7 => V121::Timeout,
+                                                    8 => V121::TooLarge,
                                                     n => {
                                                         debug_assert_eq!(
-                                                            n, 6,
+                                                            n, 9,
                                                             "invalid enum discriminant"
                                                         );
-                                                        V69::Upgrade
+                                                        let e121 = {
+                                                            let l118 =
+                                                                *ptr59.add(44).cast::<*mut u8>();
+                                                            let l119 =
+                                                                *ptr59.add(48).cast::<usize>();
+                                                            let len120 = l119;
+                                                            let bytes120 = _rt::Vec::from_raw_parts(
+                                                                l118.cast(),
+                                                                len120,
+                                                                len120,
+                                                            );
+                                                            _rt::string_lift(bytes120)
+                                                        };
+                                                        V121::Denied(e121)
                                                     }
                                                 };
-
-                                                v69
+                                                v121
                                             };
                                             Some(e)
                                         }
@@ -486,20 +875,87 @@ pub fn call(input: &GuestToHost) -> HostToGuest {
                         _ => _rt::invalid_enum_discriminant(),
                     }
                 };
-                V72::HttpResponse(e72)
+                V142::HttpResponse(e142)
             }
-            n => {
-                debug_assert_eq!(n, 4, "invalid enum discriminant");
-                let e72 = {
-                    let l70 = *ptr20.add(16).cast::<i64>();
-                    let l71 = *ptr20.add(24).cast::<i32>();
-
+            4 => {
+                let e142 = {
+                    let l122 = *ptr59.add(16).cast::<i64>();
+                    let l123 = *ptr59.add(24).cast::<i32>();
+                    component::workflow::abi::SystemTime {
+                        tv_sec: l122 as u64,
+                        tv_nsec: l123 as u32,
+                    }
+                };
+                V142::Time(e142)
+            }
+            5 => {
+                let e142 = {
+                    let l124 = *ptr59.add(16).cast::<*mut u8>();
+                    let l125 = *ptr59.add(20).cast::<usize>();
+                    let len126 = l125;
+                    _rt::Vec::from_raw_parts(l124.cast(), len126, len126)
+                };
+                V142::Input(e142)
+            }
+            6 => {
+                let e142 = {
+                    let l127 = *ptr59.add(16).cast::<i64>();
+                    let l128 = *ptr59.add(24).cast::<i32>();
                     component::workflow::abi::SystemTime {
-                        tv_sec: l70 as u64,
-                        tv_nsec: l71 as u32,
+                        tv_sec: l127 as u64,
+                        tv_nsec: l128 as u32,
+                    }
+                };
+                V142::Timer(e142)
+            }
+            7 => {
+                let e142 = {
+                    let l129 = *ptr59.add(16).cast::<*mut u8>();
+                    let l130 = *ptr59.add(20).cast::<usize>();
+                    let len131 = l130;
+                    _rt::Vec::from_raw_parts(l129.cast(), len131, len131)
+                };
+                V142::Signal(e142)
+            }
+            8 => {
+                let e142 = {
+                    let l132 = *ptr59.add(16).cast::<*mut u8>();
+                    let l133 = *ptr59.add(20).cast::<usize>();
+                    let len134 = l133;
+                    let bytes134 = _rt::Vec::from_raw_parts(l132.cast(), len134, len134);
+                    _rt::string_lift(bytes134)
+                };
+                V142::Child(e142)
+            }
+            n => {
+                debug_assert_eq!(n, 9, "invalid enum discriminant");
+                let e142 = {
+                    let l135 = i32::from(*ptr59.add(16).cast::<u8>());
+                    match l135 {
+                        0 => {
+                            let e = {
+                                let l136 = *ptr59.add(20).cast::<*mut u8>();
+                                let l137 = *ptr59.add(24).cast::<usize>();
+                                let len138 = l137;
+                                _rt::Vec::from_raw_parts(l136.cast(), len138, len138)
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l139 = *ptr59.add(20).cast::<*mut u8>();
+                                let l140 = *ptr59.add(24).cast::<usize>();
+                                let len141 = l140;
+                                let bytes141 =
+                                    _rt::Vec::from_raw_parts(l139.cast(), len141, len141);
+                                _rt::string_lift(bytes141)
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
                     }
                 };
-                V72::Time(e72)
+                V142::ChildResult(e142)
             }
         };
         for (ptr, layout) in cleanup_list {
@@ -508,8 +964,8 @@ pub fn call(input: &GuestToHost) -> HostToGuest {
             }
         }
         component::workflow::abi::HostToGuest {
-            id: _rt::string_lift(bytes23),
-            content: v72,
+            id: _rt::string_lift(bytes62),
+            content: v142,
         }
     }
 }
@@ -521,35 +977,41 @@ pub unsafe fn _export_execute_cabi<T: Guest>() -> *mut u8 {
     let result0 = T::execute();
     let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
     match result0 {
-        Ok(_) => {
+        Ok(e) => {
             *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+            let vec2 = (e).into_boxed_slice();
+            let ptr2 = vec2.as_ptr().cast::<u8>();
+            let len2 = vec2.len();
+            ::core::mem::forget(vec2);
+            *ptr1.add(8).cast::<usize>() = len2;
+            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
         }
         Err(e) => {
             *ptr1.add(0).cast::<u8>() = (1i32) as u8;
             let component::workflow::abi::WorkflowError {
-                id: id2,
-                error: error2,
+                id: id3,
+                error: error3,
             } = e;
-            match id2 {
+            match id3 {
                 Some(e) => {
                     *ptr1.add(4).cast::<u8>() = (1i32) as u8;
-                    let vec3 = (e.into_bytes()).into_boxed_slice();
-                    let ptr3 = vec3.as_ptr().cast::<u8>();
-                    let len3 = vec3.len();
-                    ::core::mem::forget(vec3);
-                    *ptr1.add(12).cast::<usize>() = len3;
-                    *ptr1.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr1.add(12).cast::<usize>() = len4;
+                    *ptr1.add(8).cast::<*mut u8>() = ptr4.cast_mut();
                 }
                 None => {
                     *ptr1.add(4).cast::<u8>() = (0i32) as u8;
                 }
             };
-            let vec4 = (error2.into_bytes()).into_boxed_slice();
-            let ptr4 = vec4.as_ptr().cast::<u8>();
-            let len4 = vec4.len();
-            ::core::mem::forget(vec4);
-            *ptr1.add(20).cast::<usize>() = len4;
-            *ptr1.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+            let vec5 = (error3.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr1.add(20).cast::<usize>() = len5;
+            *ptr1.add(16).cast::<*mut u8>() = ptr5.cast_mut();
         }
     };
     ptr1
@@ -559,40 +1021,42 @@ pub unsafe fn _export_execute_cabi<T: Guest>() -> *mut u8 {
 pub unsafe fn __post_return_execute<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
-        0 => (),
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
         _ => {
-            let l1 = i32::from(*arg0.add(4).cast::<u8>());
-            match l1 {
-                0 => (),
+            let l4 = i32::from(*arg0.add(4).cast::<u8>());
+            match l4 {
+                0 => {}
                 _ => {
-                    let l2 = *arg0.add(8).cast::<*mut u8>();
-                    let l3 = *arg0.add(12).cast::<usize>();
-                    _rt::cabi_dealloc(l2, l3, 1);
+                    let l5 = *arg0.add(8).cast::<*mut u8>();
+                    let l6 = *arg0.add(12).cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
                 }
             }
-            let l4 = *arg0.add(16).cast::<*mut u8>();
-            let l5 = *arg0.add(20).cast::<usize>();
-            _rt::cabi_dealloc(l4, l5, 1);
+            let l7 = *arg0.add(16).cast::<*mut u8>();
+            let l8 = *arg0.add(20).cast::<usize>();
+            _rt::cabi_dealloc(l7, l8, 1);
         }
     }
 }
 pub trait Guest {
-    fn execute() -> Result<(), WorkflowError>;
+    fn execute() -> Result<_rt::Vec<u8>, WorkflowError>;
 }
 #[doc(hidden)]
 #[macro_export]
-macro_rules! __export_world_workflow_cabi{
-  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
-
-    #[export_name = "execute"]
-    unsafe extern "C" fn export_execute() -> *mut u8 {
-      $($path_to_types)*::_export_execute_cabi::<$ty>()
-    }
-    #[export_name = "cabi_post_execute"]
-    unsafe extern "C" fn _post_return_execute(arg0: *mut u8,) {
-      $($path_to_types)*::__post_return_execute::<$ty>(arg0)
-    }
-  };);
+macro_rules! __export_world_workflow_cabi {
+    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+        const _ : () = { #[export_name = "execute"] unsafe extern "C" fn export_execute()
+        -> * mut u8 { $($path_to_types)*:: _export_execute_cabi::<$ty > () }
+        #[export_name = "cabi_post_execute"] unsafe extern "C" fn
+        _post_return_execute(arg0 : * mut u8,) { $($path_to_types)*::
+        __post_return_execute::<$ty > (arg0) } };
+    };
 }
 #[doc(hidden)]
 pub use __export_world_workflow_cabi;
@@ -607,44 +1071,66 @@ pub mod component {
         pub mod http {
             #[used]
             #[doc(hidden)]
-            #[cfg(target_arch = "wasm32")]
             static __FORCE_SECTION_REF: fn() =
                 super::super::super::__link_custom_section_describing_imports;
             use super::super::super::_rt;
+            #[derive(Clone, Copy)]
+            pub enum RedirectPolicy {
+                None,
+                Limited(u32),
+            }
+            impl ::core::fmt::Debug for RedirectPolicy {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        RedirectPolicy::None => f.debug_tuple("RedirectPolicy::None").finish(),
+                        RedirectPolicy::Limited(e) => {
+                            f.debug_tuple("RedirectPolicy::Limited").field(e).finish()
+                        }
+                    }
+                }
+            }
             #[repr(u8)]
             #[derive(Clone, Copy, Eq, PartialEq)]
             pub enum Method {
                 Get,
                 Post,
+                Put,
+                Patch,
                 Delete,
+                Head,
+                Options,
             }
             impl ::core::fmt::Debug for Method {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     match self {
                         Method::Get => f.debug_tuple("Method::Get").finish(),
                         Method::Post => f.debug_tuple("Method::Post").finish(),
+                        Method::Put => f.debug_tuple("Method::Put").finish(),
+                        Method::Patch => f.debug_tuple("Method::Patch").finish(),
                         Method::Delete => f.debug_tuple("Method::Delete").finish(),
+                        Method::Head => f.debug_tuple("Method::Head").finish(),
+                        Method::Options => f.debug_tuple("Method::Options").finish(),
                     }
                 }
             }
-
             impl Method {
                 #[doc(hidden)]
                 pub unsafe fn _lift(val: u8) -> Method {
                     if !cfg!(debug_assertions) {
                         return ::core::mem::transmute(val);
                     }
-
                     match val {
                         0 => Method::Get,
                         1 => Method::Post,
-                        2 => Method::Delete,
-
+                        2 => Method::Put,
+                        3 => Method::Patch,
+                        4 => Method::Delete,
+                        5 => Method::Head,
+                        6 => Method::Options,
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
             }
-
             #[derive(Clone)]
             pub struct Header {
                 pub key: _rt::String,
@@ -664,6 +1150,10 @@ pub mod component {
                 pub body: Option<_rt::Vec<u8>>,
                 pub path: _rt::String,
                 pub headers: _rt::Vec<Header>,
+                pub query: _rt::Vec<Header>,
+                pub timeout_ms: Option<u64>,
+                pub redirect: Option<RedirectPolicy>,
+                pub max_response_size: Option<u64>,
             }
             impl ::core::fmt::Debug for Request {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -672,6 +1162,10 @@ pub mod component {
                         .field("body", &self.body)
                         .field("path", &self.path)
                         .field("headers", &self.headers)
+                        .field("query", &self.query)
+                        .field("timeout-ms", &self.timeout_ms)
+                        .field("redirect", &self.redirect)
+                        .field("max-response-size", &self.max_response_size)
                         .finish()
                 }
             }
@@ -715,7 +1209,7 @@ pub mod component {
                         .finish()
                 }
             }
-            #[derive(Clone, Copy)]
+            #[derive(Clone)]
             pub enum Kind {
                 Builder,
                 Request,
@@ -724,6 +1218,9 @@ pub mod component {
                 Body,
                 Decode,
                 Upgrade,
+                Timeout,
+                TooLarge,
+                Denied(_rt::String),
             }
             impl ::core::fmt::Debug for Kind {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -735,6 +1232,9 @@ pub mod component {
                         Kind::Body => f.debug_tuple("Kind::Body").finish(),
                         Kind::Decode => f.debug_tuple("Kind::Decode").finish(),
                         Kind::Upgrade => f.debug_tuple("Kind::Upgrade").finish(),
+                        Kind::Timeout => f.debug_tuple("Kind::Timeout").finish(),
+                        Kind::TooLarge => f.debug_tuple("Kind::TooLarge").finish(),
+                        Kind::Denied(e) => f.debug_tuple("Kind::Denied").field(e).finish(),
                     }
                 }
             }
@@ -752,24 +1252,54 @@ pub mod component {
                 }
             }
         }
-
         #[allow(dead_code, clippy::all)]
         pub mod abi {
             #[used]
             #[doc(hidden)]
-            #[cfg(target_arch = "wasm32")]
             static __FORCE_SECTION_REF: fn() =
                 super::super::super::__link_custom_section_describing_imports;
             use super::super::super::_rt;
             pub type Request = super::super::super::component::workflow::http::Request;
             pub type Response = super::super::super::component::workflow::http::Response;
             pub type Error = super::super::super::component::workflow::http::Error;
+            #[derive(Clone, Copy)]
+            pub enum RandomType {
+                BoolType,
+                I32Type,
+                F32Type,
+                /// An alphanumeric string of the given number of characters.
+                StringType(u32),
+                U64Type,
+                F64Type,
+                /// The given number of bytes.
+                BytesType(u32),
+            }
+            impl ::core::fmt::Debug for RandomType {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        RandomType::BoolType => f.debug_tuple("RandomType::BoolType").finish(),
+                        RandomType::I32Type => f.debug_tuple("RandomType::I32Type").finish(),
+                        RandomType::F32Type => f.debug_tuple("RandomType::F32Type").finish(),
+                        RandomType::StringType(e) => {
+                            f.debug_tuple("RandomType::StringType").field(e).finish()
+                        }
+                        RandomType::U64Type => f.debug_tuple("RandomType::U64Type").finish(),
+                        RandomType::F64Type => f.debug_tuple("RandomType::F64Type").finish(),
+                        RandomType::BytesType(e) => {
+                            f.debug_tuple("RandomType::BytesType").field(e).finish()
+                        }
+                    }
+                }
+            }
             #[derive(Clone)]
             pub enum Types {
                 BoolType(bool),
                 I32Type(u32),
                 F32Type(f32),
                 StringType(_rt::String),
+                U64Type(u64),
+                F64Type(f64),
+                BytesType(_rt::Vec<u8>),
             }
             impl ::core::fmt::Debug for Types {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -780,16 +1310,47 @@ pub mod component {
                         Types::StringType(e) => {
                             f.debug_tuple("Types::StringType").field(e).finish()
                         }
+                        Types::U64Type(e) => f.debug_tuple("Types::U64Type").field(e).finish(),
+                        Types::F64Type(e) => f.debug_tuple("Types::F64Type").field(e).finish(),
+                        Types::BytesType(e) => f.debug_tuple("Types::BytesType").field(e).finish(),
                     }
                 }
             }
             #[derive(Clone)]
+            pub struct Field {
+                pub key: _rt::String,
+                pub value: _rt::String,
+            }
+            impl ::core::fmt::Debug for Field {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Field")
+                        .field("key", &self.key)
+                        .field("value", &self.value)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct LogRecord {
+                pub message: _rt::String,
+                pub target: Option<_rt::String>,
+                pub fields: _rt::Vec<Field>,
+            }
+            impl ::core::fmt::Debug for LogRecord {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("LogRecord")
+                        .field("message", &self.message)
+                        .field("target", &self.target)
+                        .field("fields", &self.fields)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
             pub enum Level {
-                Trace(_rt::String),
-                Debug(_rt::String),
-                Info(_rt::String),
-                Warn(_rt::String),
-                Error(_rt::String),
+                Trace(LogRecord),
+                Debug(LogRecord),
+                Info(LogRecord),
+                Warn(LogRecord),
+                Error(LogRecord),
             }
             impl ::core::fmt::Debug for Level {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -802,29 +1363,6 @@ pub mod component {
                     }
                 }
             }
-            #[derive(Clone)]
-            pub enum GuestToHost {
-                Input,
-                Random(Types),
-                Time,
-                HttpRequest(Request),
-                Log(Level),
-            }
-            impl ::core::fmt::Debug for GuestToHost {
-                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    match self {
-                        GuestToHost::Input => f.debug_tuple("GuestToHost::Input").finish(),
-                        GuestToHost::Random(e) => {
-                            f.debug_tuple("GuestToHost::Random").field(e).finish()
-                        }
-                        GuestToHost::Time => f.debug_tuple("GuestToHost::Time").finish(),
-                        GuestToHost::HttpRequest(e) => {
-                            f.debug_tuple("GuestToHost::HttpRequest").field(e).finish()
-                        }
-                        GuestToHost::Log(e) => f.debug_tuple("GuestToHost::Log").field(e).finish(),
-                    }
-                }
-            }
             #[repr(C)]
             #[derive(Clone, Copy)]
             pub struct SystemTime {
@@ -846,6 +1384,11 @@ pub mod component {
                 Value(Types),
                 HttpResponse(Result<Response, Error>),
                 Time(SystemTime),
+                Input(_rt::Vec<u8>),
+                Timer(SystemTime),
+                Signal(_rt::Vec<u8>),
+                Child(_rt::String),
+                ChildResult(Result<_rt::Vec<u8>, _rt::String>),
             }
             impl ::core::fmt::Debug for Content {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -857,6 +1400,13 @@ pub mod component {
                             f.debug_tuple("Content::HttpResponse").field(e).finish()
                         }
                         Content::Time(e) => f.debug_tuple("Content::Time").field(e).finish(),
+                        Content::Input(e) => f.debug_tuple("Content::Input").field(e).finish(),
+                        Content::Timer(e) => f.debug_tuple("Content::Timer").field(e).finish(),
+                        Content::Signal(e) => f.debug_tuple("Content::Signal").field(e).finish(),
+                        Content::Child(e) => f.debug_tuple("Content::Child").field(e).finish(),
+                        Content::ChildResult(e) => {
+                            f.debug_tuple("Content::ChildResult").field(e).finish()
+                        }
                     }
                 }
             }
@@ -873,6 +1423,82 @@ pub mod component {
                         .finish()
                 }
             }
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Duration {
+                pub secs: u64,
+                pub nanos: u32,
+            }
+            impl ::core::fmt::Debug for Duration {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Duration")
+                        .field("secs", &self.secs)
+                        .field("nanos", &self.nanos)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct ChildWorkflow {
+                pub component_name: _rt::String,
+                pub input: _rt::Vec<u8>,
+            }
+            impl ::core::fmt::Debug for ChildWorkflow {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("ChildWorkflow")
+                        .field("component-name", &self.component_name)
+                        .field("input", &self.input)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub enum GuestToHost {
+                Input,
+                Random(RandomType),
+                Time,
+                HttpRequest(Request),
+                Log(Level),
+                Sleep(Duration),
+                SleepUntil(SystemTime),
+                WaitForSignal(_rt::String),
+                StartChild(ChildWorkflow),
+                AwaitChild(_rt::String),
+                ContinueAsNew(_rt::Vec<u8>),
+            }
+            impl ::core::fmt::Debug for GuestToHost {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        GuestToHost::Input => f.debug_tuple("GuestToHost::Input").finish(),
+                        GuestToHost::Random(e) => {
+                            f.debug_tuple("GuestToHost::Random").field(e).finish()
+                        }
+                        GuestToHost::Time => f.debug_tuple("GuestToHost::Time").finish(),
+                        GuestToHost::HttpRequest(e) => {
+                            f.debug_tuple("GuestToHost::HttpRequest").field(e).finish()
+                        }
+                        GuestToHost::Log(e) => f.debug_tuple("GuestToHost::Log").field(e).finish(),
+                        GuestToHost::Sleep(e) => {
+                            f.debug_tuple("GuestToHost::Sleep").field(e).finish()
+                        }
+                        GuestToHost::SleepUntil(e) => {
+                            f.debug_tuple("GuestToHost::SleepUntil").field(e).finish()
+                        }
+                        GuestToHost::WaitForSignal(e) => f
+                            .debug_tuple("GuestToHost::WaitForSignal")
+                            .field(e)
+                            .finish(),
+                        GuestToHost::StartChild(e) => {
+                            f.debug_tuple("GuestToHost::StartChild").field(e).finish()
+                        }
+                        GuestToHost::AwaitChild(e) => {
+                            f.debug_tuple("GuestToHost::AwaitChild").field(e).finish()
+                        }
+                        GuestToHost::ContinueAsNew(e) => f
+                            .debug_tuple("GuestToHost::ContinueAsNew")
+                            .field(e)
+                            .finish(),
+                    }
+                }
+            }
             #[derive(Clone)]
             pub struct WorkflowError {
                 pub id: Option<_rt::String>,
@@ -905,44 +1531,66 @@ pub mod exports {
             pub mod http {
                 #[used]
                 #[doc(hidden)]
-                #[cfg(target_arch = "wasm32")]
                 static __FORCE_SECTION_REF: fn() =
                     super::super::super::super::__link_custom_section_describing_imports;
                 use super::super::super::super::_rt;
+                #[derive(Clone, Copy)]
+                pub enum RedirectPolicy {
+                    None,
+                    Limited(u32),
+                }
+                impl ::core::fmt::Debug for RedirectPolicy {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            RedirectPolicy::None => f.debug_tuple("RedirectPolicy::None").finish(),
+                            RedirectPolicy::Limited(e) => {
+                                f.debug_tuple("RedirectPolicy::Limited").field(e).finish()
+                            }
+                        }
+                    }
+                }
                 #[repr(u8)]
                 #[derive(Clone, Copy, Eq, PartialEq)]
                 pub enum Method {
                     Get,
                     Post,
+                    Put,
+                    Patch,
                     Delete,
+                    Head,
+                    Options,
                 }
                 impl ::core::fmt::Debug for Method {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                         match self {
                             Method::Get => f.debug_tuple("Method::Get").finish(),
                             Method::Post => f.debug_tuple("Method::Post").finish(),
+                            Method::Put => f.debug_tuple("Method::Put").finish(),
+                            Method::Patch => f.debug_tuple("Method::Patch").finish(),
                             Method::Delete => f.debug_tuple("Method::Delete").finish(),
+                            Method::Head => f.debug_tuple("Method::Head").finish(),
+                            Method::Options => f.debug_tuple("Method::Options").finish(),
                         }
                     }
                 }
-
                 impl Method {
                     #[doc(hidden)]
                     pub unsafe fn _lift(val: u8) -> Method {
                         if !cfg!(debug_assertions) {
                             return ::core::mem::transmute(val);
                         }
-
                         match val {
                             0 => Method::Get,
                             1 => Method::Post,
-                            2 => Method::Delete,
-
+                            2 => Method::Put,
+                            3 => Method::Patch,
+                            4 => Method::Delete,
+                            5 => Method::Head,
+                            6 => Method::Options,
                             _ => panic!("invalid enum discriminant"),
                         }
                     }
                 }
-
                 #[derive(Clone)]
                 pub struct Header {
                     pub key: _rt::String,
@@ -962,6 +1610,10 @@ pub mod exports {
                     pub body: Option<_rt::Vec<u8>>,
                     pub path: _rt::String,
                     pub headers: _rt::Vec<Header>,
+                    pub query: _rt::Vec<Header>,
+                    pub timeout_ms: Option<u64>,
+                    pub redirect: Option<RedirectPolicy>,
+                    pub max_response_size: Option<u64>,
                 }
                 impl ::core::fmt::Debug for Request {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -970,6 +1622,10 @@ pub mod exports {
                             .field("body", &self.body)
                             .field("path", &self.path)
                             .field("headers", &self.headers)
+                            .field("query", &self.query)
+                            .field("timeout-ms", &self.timeout_ms)
+                            .field("redirect", &self.redirect)
+                            .field("max-response-size", &self.max_response_size)
                             .finish()
                     }
                 }
@@ -1017,7 +1673,7 @@ pub mod exports {
                             .finish()
                     }
                 }
-                #[derive(Clone, Copy)]
+                #[derive(Clone)]
                 pub enum Kind {
                     Builder,
                     Request,
@@ -1026,6 +1682,9 @@ pub mod exports {
                     Body,
                     Decode,
                     Upgrade,
+                    Timeout,
+                    TooLarge,
+                    Denied(_rt::String),
                 }
                 impl ::core::fmt::Debug for Kind {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -1037,6 +1696,9 @@ pub mod exports {
                             Kind::Body => f.debug_tuple("Kind::Body").finish(),
                             Kind::Decode => f.debug_tuple("Kind::Decode").finish(),
                             Kind::Upgrade => f.debug_tuple("Kind::Upgrade").finish(),
+                            Kind::Timeout => f.debug_tuple("Kind::Timeout").finish(),
+                            Kind::TooLarge => f.debug_tuple("Kind::TooLarge").finish(),
+                            Kind::Denied(e) => f.debug_tuple("Kind::Denied").field(e).finish(),
                         }
                     }
                 }
@@ -1063,12 +1725,10 @@ pub mod exports {
                 #[doc(hidden)]
                 pub use __export_component_workflow_http_cabi;
             }
-
             #[allow(dead_code, clippy::all)]
             pub mod abi {
                 #[used]
                 #[doc(hidden)]
-                #[cfg(target_arch = "wasm32")]
                 static __FORCE_SECTION_REF: fn() =
                     super::super::super::super::__link_custom_section_describing_imports;
                 use super::super::super::super::_rt;
@@ -1078,12 +1738,44 @@ pub mod exports {
                     super::super::super::super::exports::component::workflow::http::Response;
                 pub type Error =
                     super::super::super::super::exports::component::workflow::http::Error;
+                #[derive(Clone, Copy)]
+                pub enum RandomType {
+                    BoolType,
+                    I32Type,
+                    F32Type,
+                    /// An alphanumeric string of the given number of characters.
+                    StringType(u32),
+                    U64Type,
+                    F64Type,
+                    /// The given number of bytes.
+                    BytesType(u32),
+                }
+                impl ::core::fmt::Debug for RandomType {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            RandomType::BoolType => f.debug_tuple("RandomType::BoolType").finish(),
+                            RandomType::I32Type => f.debug_tuple("RandomType::I32Type").finish(),
+                            RandomType::F32Type => f.debug_tuple("RandomType::F32Type").finish(),
+                            RandomType::StringType(e) => {
+                                f.debug_tuple("RandomType::StringType").field(e).finish()
+                            }
+                            RandomType::U64Type => f.debug_tuple("RandomType::U64Type").finish(),
+                            RandomType::F64Type => f.debug_tuple("RandomType::F64Type").finish(),
+                            RandomType::BytesType(e) => {
+                                f.debug_tuple("RandomType::BytesType").field(e).finish()
+                            }
+                        }
+                    }
+                }
                 #[derive(Clone)]
                 pub enum Types {
                     BoolType(bool),
                     I32Type(u32),
                     F32Type(f32),
                     StringType(_rt::String),
+                    U64Type(u64),
+                    F64Type(f64),
+                    BytesType(_rt::Vec<u8>),
                 }
                 impl ::core::fmt::Debug for Types {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -1096,16 +1788,49 @@ pub mod exports {
                             Types::StringType(e) => {
                                 f.debug_tuple("Types::StringType").field(e).finish()
                             }
+                            Types::U64Type(e) => f.debug_tuple("Types::U64Type").field(e).finish(),
+                            Types::F64Type(e) => f.debug_tuple("Types::F64Type").field(e).finish(),
+                            Types::BytesType(e) => {
+                                f.debug_tuple("Types::BytesType").field(e).finish()
+                            }
                         }
                     }
                 }
                 #[derive(Clone)]
+                pub struct Field {
+                    pub key: _rt::String,
+                    pub value: _rt::String,
+                }
+                impl ::core::fmt::Debug for Field {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Field")
+                            .field("key", &self.key)
+                            .field("value", &self.value)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct LogRecord {
+                    pub message: _rt::String,
+                    pub target: Option<_rt::String>,
+                    pub fields: _rt::Vec<Field>,
+                }
+                impl ::core::fmt::Debug for LogRecord {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("LogRecord")
+                            .field("message", &self.message)
+                            .field("target", &self.target)
+                            .field("fields", &self.fields)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
                 pub enum Level {
-                    Trace(_rt::String),
-                    Debug(_rt::String),
-                    Info(_rt::String),
-                    Warn(_rt::String),
-                    Error(_rt::String),
+                    Trace(LogRecord),
+                    Debug(LogRecord),
+                    Info(LogRecord),
+                    Warn(LogRecord),
+                    Error(LogRecord),
                 }
                 impl ::core::fmt::Debug for Level {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -1118,31 +1843,6 @@ pub mod exports {
                         }
                     }
                 }
-                #[derive(Clone)]
-                pub enum GuestToHost {
-                    Input,
-                    Random(Types),
-                    Time,
-                    HttpRequest(Request),
-                    Log(Level),
-                }
-                impl ::core::fmt::Debug for GuestToHost {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                        match self {
-                            GuestToHost::Input => f.debug_tuple("GuestToHost::Input").finish(),
-                            GuestToHost::Random(e) => {
-                                f.debug_tuple("GuestToHost::Random").field(e).finish()
-                            }
-                            GuestToHost::Time => f.debug_tuple("GuestToHost::Time").finish(),
-                            GuestToHost::HttpRequest(e) => {
-                                f.debug_tuple("GuestToHost::HttpRequest").field(e).finish()
-                            }
-                            GuestToHost::Log(e) => {
-                                f.debug_tuple("GuestToHost::Log").field(e).finish()
-                            }
-                        }
-                    }
-                }
                 #[repr(C)]
                 #[derive(Clone, Copy)]
                 pub struct SystemTime {
@@ -1164,6 +1864,11 @@ pub mod exports {
                     Value(Types),
                     HttpResponse(Result<Response, Error>),
                     Time(SystemTime),
+                    Input(_rt::Vec<u8>),
+                    Timer(SystemTime),
+                    Signal(_rt::Vec<u8>),
+                    Child(_rt::String),
+                    ChildResult(Result<_rt::Vec<u8>, _rt::String>),
                 }
                 impl ::core::fmt::Debug for Content {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -1177,6 +1882,15 @@ pub mod exports {
                                 f.debug_tuple("Content::HttpResponse").field(e).finish()
                             }
                             Content::Time(e) => f.debug_tuple("Content::Time").field(e).finish(),
+                            Content::Input(e) => f.debug_tuple("Content::Input").field(e).finish(),
+                            Content::Timer(e) => f.debug_tuple("Content::Timer").field(e).finish(),
+                            Content::Signal(e) => {
+                                f.debug_tuple("Content::Signal").field(e).finish()
+                            }
+                            Content::Child(e) => f.debug_tuple("Content::Child").field(e).finish(),
+                            Content::ChildResult(e) => {
+                                f.debug_tuple("Content::ChildResult").field(e).finish()
+                            }
                         }
                     }
                 }
@@ -1193,6 +1907,84 @@ pub mod exports {
                             .finish()
                     }
                 }
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct Duration {
+                    pub secs: u64,
+                    pub nanos: u32,
+                }
+                impl ::core::fmt::Debug for Duration {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Duration")
+                            .field("secs", &self.secs)
+                            .field("nanos", &self.nanos)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct ChildWorkflow {
+                    pub component_name: _rt::String,
+                    pub input: _rt::Vec<u8>,
+                }
+                impl ::core::fmt::Debug for ChildWorkflow {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("ChildWorkflow")
+                            .field("component-name", &self.component_name)
+                            .field("input", &self.input)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub enum GuestToHost {
+                    Input,
+                    Random(RandomType),
+                    Time,
+                    HttpRequest(Request),
+                    Log(Level),
+                    Sleep(Duration),
+                    SleepUntil(SystemTime),
+                    WaitForSignal(_rt::String),
+                    StartChild(ChildWorkflow),
+                    AwaitChild(_rt::String),
+                    ContinueAsNew(_rt::Vec<u8>),
+                }
+                impl ::core::fmt::Debug for GuestToHost {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            GuestToHost::Input => f.debug_tuple("GuestToHost::Input").finish(),
+                            GuestToHost::Random(e) => {
+                                f.debug_tuple("GuestToHost::Random").field(e).finish()
+                            }
+                            GuestToHost::Time => f.debug_tuple("GuestToHost::Time").finish(),
+                            GuestToHost::HttpRequest(e) => {
+                                f.debug_tuple("GuestToHost::HttpRequest").field(e).finish()
+                            }
+                            GuestToHost::Log(e) => {
+                                f.debug_tuple("GuestToHost::Log").field(e).finish()
+                            }
+                            GuestToHost::Sleep(e) => {
+                                f.debug_tuple("GuestToHost::Sleep").field(e).finish()
+                            }
+                            GuestToHost::SleepUntil(e) => {
+                                f.debug_tuple("GuestToHost::SleepUntil").field(e).finish()
+                            }
+                            GuestToHost::WaitForSignal(e) => f
+                                .debug_tuple("GuestToHost::WaitForSignal")
+                                .field(e)
+                                .finish(),
+                            GuestToHost::StartChild(e) => {
+                                f.debug_tuple("GuestToHost::StartChild").field(e).finish()
+                            }
+                            GuestToHost::AwaitChild(e) => {
+                                f.debug_tuple("GuestToHost::AwaitChild").field(e).finish()
+                            }
+                            GuestToHost::ContinueAsNew(e) => f
+                                .debug_tuple("GuestToHost::ContinueAsNew")
+                                .field(e)
+                                .finish(),
+                        }
+                    }
+                }
                 #[derive(Clone)]
                 pub struct WorkflowError {
                     pub id: Option<_rt::String>,
@@ -1228,98 +2020,89 @@ pub mod exports {
 mod _rt {
     pub use alloc_crate::string::String;
     pub use alloc_crate::vec::Vec;
-
     pub fn as_i32<T: AsI32>(t: T) -> i32 {
         t.as_i32()
     }
-
     pub trait AsI32 {
         fn as_i32(self) -> i32;
     }
-
     impl<'a, T: Copy + AsI32> AsI32 for &'a T {
         fn as_i32(self) -> i32 {
             (*self).as_i32()
         }
     }
-
     impl AsI32 for i32 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
-
     impl AsI32 for u32 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
-
     impl AsI32 for i16 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
-
     impl AsI32 for u16 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
-
     impl AsI32 for i8 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
-
     impl AsI32 for u8 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
-
     impl AsI32 for char {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
-
     impl AsI32 for usize {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
-
-    pub fn as_f32<T: AsF32>(t: T) -> f32 {
-        t.as_f32()
+    pub use alloc_crate::alloc;
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
     }
-
-    pub trait AsF32 {
-        fn as_f32(self) -> f32;
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
     }
-
-    impl<'a, T: Copy + AsF32> AsF32 for &'a T {
-        fn as_f32(self) -> f32 {
-            (*self).as_f32()
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
         }
     }
-
-    impl AsF32 for f32 {
+    impl AsI64 for i64 {
         #[inline]
-        fn as_f32(self) -> f32 {
-            self as f32
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
         }
     }
-    pub use alloc_crate::alloc;
     pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
         if cfg!(debug_assertions) {
             String::from_utf8(bytes).unwrap()
@@ -1343,7 +2126,7 @@ mod _rt {
             return;
         }
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
-        alloc::dealloc(ptr as *mut u8, layout);
+        alloc::dealloc(ptr, layout);
     }
     pub unsafe fn invalid_enum_discriminant<T>() -> T {
         if cfg!(debug_assertions) {
@@ -1352,14 +2135,12 @@ mod _rt {
             core::hint::unreachable_unchecked()
         }
     }
-
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
     }
     extern crate alloc as alloc_crate;
 }
-
 /// Generates `#[no_mangle]` functions to export the specified type as the
 /// root implementation of all generated traits.
 ///
@@ -1380,75 +2161,147 @@ mod _rt {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __export_workflow_impl {
-  ($ty:ident) => (self::export!($ty with_types_in self););
-  ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
-  $($path_to_types_root)*::__export_world_workflow_cabi!($ty with_types_in $($path_to_types_root)*);
-  $($path_to_types_root)*::exports::component::workflow::http::__export_component_workflow_http_cabi!($ty with_types_in $($path_to_types_root)*::exports::component::workflow::http);
-  $($path_to_types_root)*::exports::component::workflow::abi::__export_component_workflow_abi_cabi!($ty with_types_in $($path_to_types_root)*::exports::component::workflow::abi);
-  const _: () = {
-
-    #[cfg(target_arch = "wasm32")]
-    #[link_section = "component-type:wit-bindgen:0.25.0:workflow:imports and exports"]
-    #[doc(hidden)]
-    pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2132] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xd5\x0f\x01A\x02\x01\
-A\x16\x01B\x14\x01m\x03\x03get\x04post\x06delete\x04\0\x06method\x03\0\0\x01r\x02\
-\x03keys\x05values\x04\0\x06header\x03\0\x02\x01p}\x01k\x04\x01p\x03\x01r\x04\x06\
-method\x01\x04body\x05\x04paths\x07headers\x06\x04\0\x07request\x03\0\x07\x01q\x05\
-\x0ehttp-zero-nine\0\0\x0dhttp-one-zero\0\0\x0chttp-one-one\0\0\x0dhttp-two-zero\
-\0\0\x0fhttp-three-zero\0\0\x04\0\x07version\x03\0\x09\x01kw\x01r\x06\x06status{\
-\x0chttp-version\x0a\x07headers\x06\x0econtent-length\x0b\x03urls\x04body\x04\x04\
-\0\x08response\x03\0\x0c\x01q\x07\x07builder\0\0\x07request\0\0\x08redirect\0\0\x06\
-status\x01{\0\x04body\0\0\x06decode\0\0\x07upgrade\0\0\x04\0\x04kind\x03\0\x0e\x01\
-ks\x01k\x0f\x01r\x02\x03url\x10\x04kind\x11\x04\0\x05error\x03\0\x12\x03\x01\x17\
-component:workflow/http\x05\0\x02\x03\0\0\x07request\x02\x03\0\0\x08response\x02\
-\x03\0\0\x05error\x01B\x16\x02\x03\x02\x01\x01\x04\0\x07request\x03\0\0\x02\x03\x02\
-\x01\x02\x04\0\x08response\x03\0\x02\x02\x03\x02\x01\x03\x04\0\x05error\x03\0\x04\
-\x01q\x04\x09bool-type\x01\x7f\0\x08i32-type\x01y\0\x08f32-type\x01v\0\x0bstring\
--type\x01s\0\x04\0\x05types\x03\0\x06\x01q\x05\x05trace\x01s\0\x05debug\x01s\0\x04\
-info\x01s\0\x04warn\x01s\0\x05error\x01s\0\x04\0\x05level\x03\0\x08\x01q\x05\x05\
-input\0\0\x06random\x01\x07\0\x04time\0\0\x0chttp-request\x01\x01\0\x03log\x01\x09\
-\0\x04\0\x0dguest-to-host\x03\0\x0a\x01r\x02\x06tv-secw\x07tv-nsecy\x04\0\x0bsys\
-tem-time\x03\0\x0c\x01j\x01\x03\x01\x05\x01q\x05\x04unit\0\0\x06random\x01\x07\0\
-\x05value\x01\x07\0\x0dhttp-response\x01\x0e\0\x04time\x01\x0d\0\x04\0\x07conten\
-t\x03\0\x0f\x01r\x02\x02ids\x07content\x10\x04\0\x0dhost-to-guest\x03\0\x11\x01k\
-s\x01r\x02\x02id\x13\x05errors\x04\0\x0eworkflow-error\x03\0\x14\x03\x01\x16comp\
-onent:workflow/abi\x05\x04\x02\x03\0\x01\x0dguest-to-host\x03\0\x0dguest-to-host\
-\x03\0\x05\x02\x03\0\x01\x0dhost-to-guest\x03\0\x0dhost-to-guest\x03\0\x07\x02\x03\
-\0\x01\x0eworkflow-error\x03\0\x0eworkflow-error\x03\0\x09\x01@\x01\x05input\x06\
-\0\x08\x03\0\x04call\x01\x0b\x01j\0\x01\x0a\x01@\0\0\x0c\x04\0\x07execute\x01\x0d\
-\x01B\x14\x01m\x03\x03get\x04post\x06delete\x04\0\x06method\x03\0\0\x01r\x02\x03\
-keys\x05values\x04\0\x06header\x03\0\x02\x01p}\x01k\x04\x01p\x03\x01r\x04\x06met\
-hod\x01\x04body\x05\x04paths\x07headers\x06\x04\0\x07request\x03\0\x07\x01q\x05\x0e\
-http-zero-nine\0\0\x0dhttp-one-zero\0\0\x0chttp-one-one\0\0\x0dhttp-two-zero\0\0\
-\x0fhttp-three-zero\0\0\x04\0\x07version\x03\0\x09\x01kw\x01r\x06\x06status{\x0c\
-http-version\x0a\x07headers\x06\x0econtent-length\x0b\x03urls\x04body\x04\x04\0\x08\
-response\x03\0\x0c\x01q\x07\x07builder\0\0\x07request\0\0\x08redirect\0\0\x06sta\
-tus\x01{\0\x04body\0\0\x06decode\0\0\x07upgrade\0\0\x04\0\x04kind\x03\0\x0e\x01k\
-s\x01k\x0f\x01r\x02\x03url\x10\x04kind\x11\x04\0\x05error\x03\0\x12\x04\x01\x17c\
-omponent:workflow/http\x05\x0e\x01B\x16\x02\x03\x02\x01\x01\x04\0\x07request\x03\
-\0\0\x02\x03\x02\x01\x02\x04\0\x08response\x03\0\x02\x02\x03\x02\x01\x03\x04\0\x05\
-error\x03\0\x04\x01q\x04\x09bool-type\x01\x7f\0\x08i32-type\x01y\0\x08f32-type\x01\
-v\0\x0bstring-type\x01s\0\x04\0\x05types\x03\0\x06\x01q\x05\x05trace\x01s\0\x05d\
-ebug\x01s\0\x04info\x01s\0\x04warn\x01s\0\x05error\x01s\0\x04\0\x05level\x03\0\x08\
-\x01q\x05\x05input\0\0\x06random\x01\x07\0\x04time\0\0\x0chttp-request\x01\x01\0\
-\x03log\x01\x09\0\x04\0\x0dguest-to-host\x03\0\x0a\x01r\x02\x06tv-secw\x07tv-nse\
-cy\x04\0\x0bsystem-time\x03\0\x0c\x01j\x01\x03\x01\x05\x01q\x05\x04unit\0\0\x06r\
-andom\x01\x07\0\x05value\x01\x07\0\x0dhttp-response\x01\x0e\0\x04time\x01\x0d\0\x04\
-\0\x07content\x03\0\x0f\x01r\x02\x02ids\x07content\x10\x04\0\x0dhost-to-guest\x03\
-\0\x11\x01ks\x01r\x02\x02id\x13\x05errors\x04\0\x0eworkflow-error\x03\0\x14\x04\x01\
-\x16component:workflow/abi\x05\x0f\x04\x01\x1bcomponent:workflow/workflow\x04\0\x0b\
-\x0e\x01\0\x08workflow\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-co\
-mponent\x070.208.1\x10wit-bindgen-rust\x060.25.0";
-  };
-  )
+    ($ty:ident) => {
+        self::export!($ty with_types_in self);
+    };
+    ($ty:ident with_types_in $($path_to_types_root:tt)*) => {
+        $($path_to_types_root)*:: __export_world_workflow_cabi!($ty with_types_in
+        $($path_to_types_root)*); $($path_to_types_root)*::
+        exports::component::workflow::http::__export_component_workflow_http_cabi!($ty
+        with_types_in $($path_to_types_root)*:: exports::component::workflow::http);
+        $($path_to_types_root)*::
+        exports::component::workflow::abi::__export_component_workflow_abi_cabi!($ty
+        with_types_in $($path_to_types_root)*:: exports::component::workflow::abi); const
+        _ : () = { #[cfg(target_arch = "wasm32")] #[link_section =
+        "component-type:wit-bindgen:0.30.0:workflow:imports and exports"] #[doc(hidden)]
+        pub static __WIT_BINDGEN_COMPONENT_TYPE : [u8; 3324] = *
+        b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xfd\x18\x01A\x02\x01\
+A\x17\x01B\x17\x01q\x02\x04none\0\0\x07limited\x01y\0\x04\0\x0fredirect-policy\x03\
+\0\0\x01m\x07\x03get\x04post\x03put\x05patch\x06delete\x04head\x07options\x04\0\x06\
+method\x03\0\x02\x01r\x02\x03keys\x05values\x04\0\x06header\x03\0\x04\x01p}\x01k\
+\x06\x01p\x05\x01kw\x01k\x01\x01r\x08\x06method\x03\x04body\x07\x04paths\x07head\
+ers\x08\x05query\x08\x0atimeout-ms\x09\x08redirect\x0a\x11max-response-size\x09\x04\
+\0\x07request\x03\0\x0b\x01q\x05\x0ehttp-zero-nine\0\0\x0dhttp-one-zero\0\0\x0ch\
+ttp-one-one\0\0\x0dhttp-two-zero\0\0\x0fhttp-three-zero\0\0\x04\0\x07version\x03\
+\0\x0d\x01r\x06\x06status{\x0chttp-version\x0e\x07headers\x08\x0econtent-length\x09\
+\x03urls\x04body\x06\x04\0\x08response\x03\0\x0f\x01q\x0a\x07builder\0\0\x07requ\
+est\0\0\x08redirect\0\0\x06status\x01{\0\x04body\0\0\x06decode\0\0\x07upgrade\0\0\
+\x07timeout\0\0\x09too-large\0\0\x06denied\x01s\0\x04\0\x04kind\x03\0\x11\x01ks\x01\
+k\x12\x01r\x02\x03url\x13\x04kind\x14\x04\0\x05error\x03\0\x15\x03\x01\x17compon\
+ent:workflow/http\x05\0\x02\x03\0\0\x07request\x02\x03\0\0\x08response\x02\x03\0\
+\0\x05error\x01B#\x02\x03\x02\x01\x01\x04\0\x07request\x03\0\0\x02\x03\x02\x01\x02\
+\x04\0\x08response\x03\0\x02\x02\x03\x02\x01\x03\x04\0\x05error\x03\0\x04\x01q\x07\
+\x09bool-type\0\0\x08i32-type\0\0\x08f32-type\0\0\x0bstring-type\x01y\0\x08u64-t\
+ype\0\0\x08f64-type\0\0\x0abytes-type\x01y\0\x04\0\x0brandom-type\x03\0\x06\x01p\
+}\x01q\x07\x09bool-type\x01\x7f\0\x08i32-type\x01y\0\x08f32-type\x01v\0\x0bstrin\
+g-type\x01s\0\x08u64-type\x01w\0\x08f64-type\x01u\0\x0abytes-type\x01\x08\0\x04\0\
+\x05types\x03\0\x09\x01r\x02\x03keys\x05values\x04\0\x05field\x03\0\x0b\x01ks\x01\
+p\x0c\x01r\x03\x07messages\x06target\x0d\x06fields\x0e\x04\0\x0alog-record\x03\0\
+\x0f\x01q\x05\x05trace\x01\x10\0\x05debug\x01\x10\0\x04info\x01\x10\0\x04warn\x01\
+\x10\0\x05error\x01\x10\0\x04\0\x05level\x03\0\x11\x01r\x02\x06tv-secw\x07tv-nse\
+cy\x04\0\x0bsystem-time\x03\0\x13\x01j\x01\x03\x01\x05\x01j\x01\x08\x01s\x01q\x0a\
+\x04unit\0\0\x06random\x01\x0a\0\x05value\x01\x0a\0\x0dhttp-response\x01\x15\0\x04\
+time\x01\x14\0\x05input\x01\x08\0\x05timer\x01\x14\0\x06signal\x01\x08\0\x05chil\
+d\x01s\0\x0cchild-result\x01\x16\0\x04\0\x07content\x03\0\x17\x01r\x02\x02ids\x07\
+content\x18\x04\0\x0dhost-to-guest\x03\0\x19\x01r\x02\x04secsw\x05nanosy\x04\0\x08\
+duration\x03\0\x1b\x01r\x02\x0ecomponent-names\x05input\x08\x04\0\x0echild-workf\
+low\x03\0\x1d\x01q\x0b\x05input\0\0\x06random\x01\x07\0\x04time\0\0\x0chttp-requ\
+est\x01\x01\0\x03log\x01\x12\0\x05sleep\x01\x1c\0\x0bsleep-until\x01\x14\0\x0fwa\
+it-for-signal\x01s\0\x0bstart-child\x01\x1e\0\x0bawait-child\x01s\0\x0fcontinue-\
+as-new\x01\x08\0\x04\0\x0dguest-to-host\x03\0\x1f\x01r\x02\x02id\x0d\x05errors\x04\
+\0\x0eworkflow-error\x03\0!\x03\x01\x16component:workflow/abi\x05\x04\x02\x03\0\x01\
+\x0dguest-to-host\x03\0\x0dguest-to-host\x03\0\x05\x02\x03\0\x01\x0dhost-to-gues\
+t\x03\0\x0dhost-to-guest\x03\0\x07\x02\x03\0\x01\x0eworkflow-error\x03\0\x0ework\
+flow-error\x03\0\x09\x01@\x01\x05input\x06\0\x08\x03\0\x04call\x01\x0b\x01p}\x01\
+j\x01\x0c\x01\x0a\x01@\0\0\x0d\x04\0\x07execute\x01\x0e\x01B\x17\x01q\x02\x04non\
+e\0\0\x07limited\x01y\0\x04\0\x0fredirect-policy\x03\0\0\x01m\x07\x03get\x04post\
+\x03put\x05patch\x06delete\x04head\x07options\x04\0\x06method\x03\0\x02\x01r\x02\
+\x03keys\x05values\x04\0\x06header\x03\0\x04\x01p}\x01k\x06\x01p\x05\x01kw\x01k\x01\
+\x01r\x08\x06method\x03\x04body\x07\x04paths\x07headers\x08\x05query\x08\x0atime\
+out-ms\x09\x08redirect\x0a\x11max-response-size\x09\x04\0\x07request\x03\0\x0b\x01\
+q\x05\x0ehttp-zero-nine\0\0\x0dhttp-one-zero\0\0\x0chttp-one-one\0\0\x0dhttp-two\
+-zero\0\0\x0fhttp-three-zero\0\0\x04\0\x07version\x03\0\x0d\x01r\x06\x06status{\x0c\
+http-version\x0e\x07headers\x08\x0econtent-length\x09\x03urls\x04body\x06\x04\0\x08\
+response\x03\0\x0f\x01q\x0a\x07builder\0\0\x07request\0\0\x08redirect\0\0\x06sta\
+tus\x01{\0\x04body\0\0\x06decode\0\0\x07upgrade\0\0\x07timeout\0\0\x09too-large\0\
+\0\x06denied\x01s\0\x04\0\x04kind\x03\0\x11\x01ks\x01k\x12\x01r\x02\x03url\x13\x04\
+kind\x14\x04\0\x05error\x03\0\x15\x04\x01\x17component:workflow/http\x05\x0f\x01\
+B#\x02\x03\x02\x01\x01\x04\0\x07request\x03\0\0\x02\x03\x02\x01\x02\x04\0\x08res\
+ponse\x03\0\x02\x02\x03\x02\x01\x03\x04\0\x05error\x03\0\x04\x01q\x07\x09bool-ty\
+pe\0\0\x08i32-type\0\0\x08f32-type\0\0\x0bstring-type\x01y\0\x08u64-type\0\0\x08\
+f64-type\0\0\x0abytes-type\x01y\0\x04\0\x0brandom-type\x03\0\x06\x01p}\x01q\x07\x09\
+bool-type\x01\x7f\0\x08i32-type\x01y\0\x08f32-type\x01v\0\x0bstring-type\x01s\0\x08\
+u64-type\x01w\0\x08f64-type\x01u\0\x0abytes-type\x01\x08\0\x04\0\x05types\x03\0\x09\
+\x01r\x02\x03keys\x05values\x04\0\x05field\x03\0\x0b\x01ks\x01p\x0c\x01r\x03\x07\
+messages\x06target\x0d\x06fields\x0e\x04\0\x0alog-record\x03\0\x0f\x01q\x05\x05t\
+race\x01\x10\0\x05debug\x01\x10\0\x04info\x01\x10\0\x04warn\x01\x10\0\x05error\x01\
+\x10\0\x04\0\x05level\x03\0\x11\x01r\x02\x06tv-secw\x07tv-nsecy\x04\0\x0bsystem-\
+time\x03\0\x13\x01j\x01\x03\x01\x05\x01j\x01\x08\x01s\x01q\x0a\x04unit\0\0\x06ra\
+ndom\x01\x0a\0\x05value\x01\x0a\0\x0dhttp-response\x01\x15\0\x04time\x01\x14\0\x05\
+input\x01\x08\0\x05timer\x01\x14\0\x06signal\x01\x08\0\x05child\x01s\0\x0cchild-\
+result\x01\x16\0\x04\0\x07content\x03\0\x17\x01r\x02\x02ids\x07content\x18\x04\0\
+\x0dhost-to-guest\x03\0\x19\x01r\x02\x04secsw\x05nanosy\x04\0\x08duration\x03\0\x1b\
+\x01r\x02\x0ecomponent-names\x05input\x08\x04\0\x0echild-workflow\x03\0\x1d\x01q\
+\x0b\x05input\0\0\x06random\x01\x07\0\x04time\0\0\x0chttp-request\x01\x01\0\x03l\
+og\x01\x12\0\x05sleep\x01\x1c\0\x0bsleep-until\x01\x14\0\x0fwait-for-signal\x01s\
+\0\x0bstart-child\x01\x1e\0\x0bawait-child\x01s\0\x0fcontinue-as-new\x01\x08\0\x04\
+\0\x0dguest-to-host\x03\0\x1f\x01r\x02\x02id\x0d\x05errors\x04\0\x0eworkflow-err\
+or\x03\0!\x04\x01\x16component:workflow/abi\x05\x10\x04\x01\x1bcomponent:workflo\
+w/workflow\x04\0\x0b\x0e\x01\0\x08workflow\x03\0\0\0G\x09producers\x01\x0cproces\
+sed-by\x02\x0dwit-component\x070.215.0\x10wit-bindgen-rust\x060.30.0";
+        };
+    };
 }
 #[doc(inline)]
 pub use __export_workflow_impl as export;
-
+#[cfg(target_arch = "wasm32")]
+#[link_section = "component-type:wit-bindgen:0.30.0:workflow-with-all-of-its-exports-removed:encoded world"]
+#[doc(hidden)]
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1880] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xb9\x0d\x01A\x02\x01\
+A\x0f\x01B\x17\x01q\x02\x04none\0\0\x07limited\x01y\0\x04\0\x0fredirect-policy\x03\
+\0\0\x01m\x07\x03get\x04post\x03put\x05patch\x06delete\x04head\x07options\x04\0\x06\
+method\x03\0\x02\x01r\x02\x03keys\x05values\x04\0\x06header\x03\0\x04\x01p}\x01k\
+\x06\x01p\x05\x01kw\x01k\x01\x01r\x08\x06method\x03\x04body\x07\x04paths\x07head\
+ers\x08\x05query\x08\x0atimeout-ms\x09\x08redirect\x0a\x11max-response-size\x09\x04\
+\0\x07request\x03\0\x0b\x01q\x05\x0ehttp-zero-nine\0\0\x0dhttp-one-zero\0\0\x0ch\
+ttp-one-one\0\0\x0dhttp-two-zero\0\0\x0fhttp-three-zero\0\0\x04\0\x07version\x03\
+\0\x0d\x01r\x06\x06status{\x0chttp-version\x0e\x07headers\x08\x0econtent-length\x09\
+\x03urls\x04body\x06\x04\0\x08response\x03\0\x0f\x01q\x0a\x07builder\0\0\x07requ\
+est\0\0\x08redirect\0\0\x06status\x01{\0\x04body\0\0\x06decode\0\0\x07upgrade\0\0\
+\x07timeout\0\0\x09too-large\0\0\x06denied\x01s\0\x04\0\x04kind\x03\0\x11\x01ks\x01\
+k\x12\x01r\x02\x03url\x13\x04kind\x14\x04\0\x05error\x03\0\x15\x03\x01\x17compon\
+ent:workflow/http\x05\0\x02\x03\0\0\x07request\x02\x03\0\0\x08response\x02\x03\0\
+\0\x05error\x01B#\x02\x03\x02\x01\x01\x04\0\x07request\x03\0\0\x02\x03\x02\x01\x02\
+\x04\0\x08response\x03\0\x02\x02\x03\x02\x01\x03\x04\0\x05error\x03\0\x04\x01q\x07\
+\x09bool-type\0\0\x08i32-type\0\0\x08f32-type\0\0\x0bstring-type\x01y\0\x08u64-t\
+ype\0\0\x08f64-type\0\0\x0abytes-type\x01y\0\x04\0\x0brandom-type\x03\0\x06\x01p\
+}\x01q\x07\x09bool-type\x01\x7f\0\x08i32-type\x01y\0\x08f32-type\x01v\0\x0bstrin\
+g-type\x01s\0\x08u64-type\x01w\0\x08f64-type\x01u\0\x0abytes-type\x01\x08\0\x04\0\
+\x05types\x03\0\x09\x01r\x02\x03keys\x05values\x04\0\x05field\x03\0\x0b\x01ks\x01\
+p\x0c\x01r\x03\x07messages\x06target\x0d\x06fields\x0e\x04\0\x0alog-record\x03\0\
+\x0f\x01q\x05\x05trace\x01\x10\0\x05debug\x01\x10\0\x04info\x01\x10\0\x04warn\x01\
+\x10\0\x05error\x01\x10\0\x04\0\x05level\x03\0\x11\x01r\x02\x06tv-secw\x07tv-nse\
+cy\x04\0\x0bsystem-time\x03\0\x13\x01j\x01\x03\x01\x05\x01j\x01\x08\x01s\x01q\x0a\
+\x04unit\0\0\x06random\x01\x0a\0\x05value\x01\x0a\0\x0dhttp-response\x01\x15\0\x04\
+time\x01\x14\0\x05input\x01\x08\0\x05timer\x01\x14\0\x06signal\x01\x08\0\x05chil\
+d\x01s\0\x0cchild-result\x01\x16\0\x04\0\x07content\x03\0\x17\x01r\x02\x02ids\x07\
+content\x18\x04\0\x0dhost-to-guest\x03\0\x19\x01r\x02\x04secsw\x05nanosy\x04\0\x08\
+duration\x03\0\x1b\x01r\x02\x0ecomponent-names\x05input\x08\x04\0\x0echild-workf\
+low\x03\0\x1d\x01q\x0b\x05input\0\0\x06random\x01\x07\0\x04time\0\0\x0chttp-requ\
+est\x01\x01\0\x03log\x01\x12\0\x05sleep\x01\x1c\0\x0bsleep-until\x01\x14\0\x0fwa\
+it-for-signal\x01s\0\x0bstart-child\x01\x1e\0\x0bawait-child\x01s\0\x0fcontinue-\
+as-new\x01\x08\0\x04\0\x0dguest-to-host\x03\0\x1f\x01r\x02\x02id\x0d\x05errors\x04\
+\0\x0eworkflow-error\x03\0!\x03\x01\x16component:workflow/abi\x05\x04\x02\x03\0\x01\
+\x0dguest-to-host\x03\0\x0dguest-to-host\x03\0\x05\x02\x03\0\x01\x0dhost-to-gues\
+t\x03\0\x0dhost-to-guest\x03\0\x07\x02\x03\0\x01\x0eworkflow-error\x03\0\x0ework\
+flow-error\x03\0\x09\x01@\x01\x05input\x06\0\x08\x03\0\x04call\x01\x0b\x04\x01;c\
+omponent:workflow/workflow-with-all-of-its-exports-removed\x04\0\x0b.\x01\0(work\
+flow-with-all-of-its-exports-removed\x03\0\0\0G\x09producers\x01\x0cprocessed-by\
+\x02\x0dwit-component\x070.215.0\x10wit-bindgen-rust\x060.30.0";
 #[inline(never)]
 #[doc(hidden)]
-#[cfg(target_arch = "wasm32")]
 pub fn __link_custom_section_describing_imports() {
     wit_bindgen_rt::maybe_link_cabi_realloc();
 }
@@ -0,0 +1,98 @@
+use crate::{
+    bindings::{
+        self,
+        component::workflow::abi::{ChildWorkflow, Content, GuestToHost, HostToGuest},
+        WorkflowError,
+    },
+    Result,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A handle to a child workflow started with [`start_child`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Child {
+    id: String,
+}
+
+impl Child {
+    /// Get the workflow id of this child.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Starts a component registered on the host as a child workflow.
+///
+/// The child runs as its own workflow with its own journal. The returned handle is journaled
+/// in this workflow so replays do not launch the child again.
+///
+/// # Arguments
+///
+/// * `component_name` - The name the component was registered with on the host.
+/// * `input` - The input the child is started with.
+///
+/// # Returns
+///
+/// A `Result` containing a handle to await the child with, or a `WorkflowError` if the input
+/// could not be serialized.
+///
+/// # Panics
+///
+/// This function will panic if the host returns an unexpected response format.
+///
+/// # Example
+///
+/// ```
+/// let child = start_child("send-invoice", &order)?;
+/// let receipt = await_child::<String>(&child)?;
+/// ```
+pub fn start_child<I: Serialize>(component_name: impl Into<String>, input: &I) -> Result<Child> {
+    let input = serde_json::to_vec(input)
+        .map_err(|err| WorkflowError::new(None, format!("invalid child input: {err}")))?;
+
+    match bindings::call(&GuestToHost::StartChild(ChildWorkflow {
+        component_name: component_name.into(),
+        input,
+    })) {
+        HostToGuest {
+            content: Content::Child(id),
+            ..
+        } => Ok(Child { id }),
+        m => {
+            log::error!("expected Content::Child got {:?}", m);
+            unreachable!()
+        }
+    }
+}
+
+/// Waits for a child workflow to finish and returns its output.
+///
+/// If the child is still running the workflow is parked by the host and resumed by replay
+/// once the child has finished.
+///
+/// # Returns
+///
+/// A `Result` containing the deserialized output of the child, or a `WorkflowError` if the
+/// child failed or its output could not be deserialized into `T`.
+///
+/// # Panics
+///
+/// This function will panic if the host returns an unexpected response format.
+pub fn await_child<T: DeserializeOwned>(child: &Child) -> Result<T> {
+    match bindings::call(&GuestToHost::AwaitChild(child.id.clone())) {
+        HostToGuest {
+            content: Content::ChildResult(result),
+            ..
+        } => {
+            let output = result.map_err(|err| {
+                WorkflowError::new(None, format!("child workflow {} failed: {err}", child.id))
+            })?;
+            serde_json::from_slice(&output)
+                .map_err(|err| WorkflowError::new(None, format!("invalid child output: {err}")))
+        }
+        m => {
+            log::error!("expected Content::ChildResult got {:?}", m);
+            unreachable!()
+        }
+    }
+}
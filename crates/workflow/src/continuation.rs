@@ -0,0 +1,30 @@
+use crate::{
+    bindings::{self, component::workflow::abi::GuestToHost, WorkflowError},
+    Result,
+};
+use serde::Serialize;
+
+/// Finishes the current run of the workflow and starts it again with the given input.
+///
+/// The journal of the workflow is dropped, so a workflow that loops forever, e.g. polling an
+/// API every hour, keeps its history and replay cost bounded. The new run keeps the id of the
+/// workflow and receives any signals that were not delivered yet.
+///
+/// This function only returns if the input cannot be serialized.
+///
+/// # Example
+///
+/// ```
+/// let cursor = input::<u64>()?;
+/// let cursor = poll(cursor)?;
+/// sleep(Duration::from_secs(60 * 60));
+/// continue_as_new(&cursor)?;
+/// ```
+pub fn continue_as_new<T: Serialize>(input: &T) -> Result<()> {
+    let input = serde_json::to_vec(input)
+        .map_err(|err| WorkflowError::new(None, format!("invalid input: {err}")))?;
+    let message = bindings::call(&GuestToHost::ContinueAsNew(input));
+    // The host traps the guest to start the new run, so the call never returns.
+    log::error!("expected the workflow to continue as new got {:?}", message);
+    unreachable!()
+}
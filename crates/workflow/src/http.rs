@@ -9,11 +9,15 @@ use crate::{
     },
     Result,
 };
+pub use ::http::Method;
 use ::http::{StatusCode, Version};
+pub use client::{Client, RequestBuilder};
 use serde::de::DeserializeOwned;
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 use uuid::Uuid;
 
+mod client;
+
 /// Performs a GET request to the specified path.
 ///
 /// # Arguments
@@ -49,6 +53,46 @@ pub fn post(
     request(http::Method::Post, path, headers, body)
 }
 
+/// Performs a PUT request to the specified path.
+///
+/// # Arguments
+///
+/// * `path` - The URL path for the request.
+/// * `headers` - Optional headers to include in the request.
+/// * `body` - Optional body content for the request.
+///
+/// # Returns
+///
+/// A `Result` containing the `Response` if successful, or an `Error` if the request fails.
+#[allow(unused)]
+pub fn put(
+    path: impl Into<String>,
+    headers: Option<HashMap<&str, &str>>,
+    body: Option<Vec<u8>>,
+) -> Result<Response> {
+    request(http::Method::Put, path, headers, body)
+}
+
+/// Performs a PATCH request to the specified path.
+///
+/// # Arguments
+///
+/// * `path` - The URL path for the request.
+/// * `headers` - Optional headers to include in the request.
+/// * `body` - Optional body content for the request.
+///
+/// # Returns
+///
+/// A `Result` containing the `Response` if successful, or an `Error` if the request fails.
+#[allow(unused)]
+pub fn patch(
+    path: impl Into<String>,
+    headers: Option<HashMap<&str, &str>>,
+    body: Option<Vec<u8>>,
+) -> Result<Response> {
+    request(http::Method::Patch, path, headers, body)
+}
+
 /// Performs a DELETE request to the specified path.
 ///
 /// # Arguments
@@ -69,6 +113,100 @@ pub fn delete(
     request(http::Method::Delete, path, headers, body)
 }
 
+/// Performs a HEAD request to the specified path.
+///
+/// # Arguments
+///
+/// * `path` - The URL path for the request.
+/// * `headers` - Optional headers to include in the request.
+///
+/// # Returns
+///
+/// A `Result` containing the `Response` if successful, or an `Error` if the request fails.
+#[allow(unused)]
+pub fn head(path: impl Into<String>, headers: Option<HashMap<&str, &str>>) -> Result<Response> {
+    request(http::Method::Head, path, headers, None)
+}
+
+/// Performs an OPTIONS request to the specified path.
+///
+/// # Arguments
+///
+/// * `path` - The URL path for the request.
+/// * `headers` - Optional headers to include in the request.
+///
+/// # Returns
+///
+/// A `Result` containing the `Response` if successful, or an `Error` if the request fails.
+#[allow(unused)]
+pub fn options(path: impl Into<String>, headers: Option<HashMap<&str, &str>>) -> Result<Response> {
+    request(http::Method::Options, path, headers, None)
+}
+
+/// How redirects returned by the server are handled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Redirect {
+    /// Redirects are returned to the caller as the response.
+    None,
+    /// Redirects are followed up to the given number of hops.
+    Limited(u32),
+}
+
+/// Additional settings of a single request.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    /// Query parameters appended to the URL.
+    pub query: Vec<(String, String)>,
+    /// Time after which the request fails with `Kind::Timeout`.
+    pub timeout: Option<Duration>,
+    /// How redirects are handled, or `None` to follow up to 10 redirects.
+    pub redirect: Option<Redirect>,
+    /// Size in bytes after which the response fails with `Kind::TooLarge`.
+    pub max_response_size: Option<u64>,
+}
+
+/// Performs a request with the given method and options.
+///
+/// # Arguments
+///
+/// * `method` - The HTTP method for the request.
+/// * `path` - The URL path for the request.
+/// * `headers` - Optional headers to include in the request.
+/// * `body` - Optional body content for the request.
+/// * `options` - Query parameters, timeout, redirect policy and size limit of the request.
+///
+/// # Returns
+///
+/// A `Result` containing the `Response` if successful, or an `Error` if the request fails.
+#[allow(unused)]
+pub fn request_with(
+    method: Method,
+    path: impl Into<String>,
+    headers: Option<HashMap<&str, &str>>,
+    body: Option<Vec<u8>>,
+    options: Options,
+) -> Result<Response> {
+    send(to_method(method)?, path, to_headers(headers), body, options)
+}
+
+fn to_method(method: Method) -> Result<http::Method> {
+    Ok(match method {
+        Method::GET => http::Method::Get,
+        Method::POST => http::Method::Post,
+        Method::PUT => http::Method::Put,
+        Method::PATCH => http::Method::Patch,
+        Method::DELETE => http::Method::Delete,
+        Method::HEAD => http::Method::Head,
+        Method::OPTIONS => http::Method::Options,
+        method => {
+            return Err(WorkflowError::new(
+                None,
+                format!("unsupported HTTP method {method}"),
+            ))
+        }
+    })
+}
+
 /// Internal function to perform an HTTP request.
 ///
 /// # Arguments
@@ -87,21 +225,63 @@ fn request(
     headers: Option<HashMap<&str, &str>>,
     body: Option<Vec<u8>>,
 ) -> Result<Response> {
-    let request = GuestToHost::HttpRequest(http::Request {
+    send(method, path, to_headers(headers), body, Options::default())
+}
+
+fn to_headers(headers: Option<HashMap<&str, &str>>) -> Vec<http::Header> {
+    headers
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(k, v)| http::Header {
+            key: k.into(),
+            value: v.into(),
+        })
+        .collect()
+}
+
+/// Sends a request to the host and waits for its response.
+fn send(
+    method: http::Method,
+    path: impl Into<String>,
+    headers: Vec<http::Header>,
+    body: Option<Vec<u8>>,
+    options: Options,
+) -> Result<Response> {
+    call(to_request(method, path, headers, body, options))
+}
+
+/// Builds the request sent to the host.
+fn to_request(
+    method: http::Method,
+    path: impl Into<String>,
+    headers: Vec<http::Header>,
+    body: Option<Vec<u8>>,
+    options: Options,
+) -> http::Request {
+    http::Request {
         method,
         path: path.into(),
         body,
-        headers: headers
-            .unwrap_or_default()
+        headers,
+        query: options
+            .query
             .into_iter()
-            .map(|(k, v)| http::Header {
-                key: k.into(),
-                value: v.into(),
-            })
-            .collect::<Vec<_>>(),
-    });
-
-    match bindings::call(&request) {
+            .map(|(key, value)| http::Header { key, value })
+            .collect(),
+        timeout_ms: options
+            .timeout
+            .map(|timeout| timeout.as_millis().try_into().unwrap_or(u64::MAX)),
+        redirect: options.redirect.map(|redirect| match redirect {
+            Redirect::None => http::RedirectPolicy::None,
+            Redirect::Limited(max) => http::RedirectPolicy::Limited(max),
+        }),
+        max_response_size: options.max_response_size,
+    }
+}
+
+/// Sends the request to the host and waits for its response.
+fn call(request: http::Request) -> Result<Response> {
+    match bindings::call(&GuestToHost::HttpRequest(request)) {
         HostToGuest {
             id,
             content: Content::HttpResponse(response),
@@ -268,6 +448,9 @@ impl Error {
                 http::Kind::Body => Kind::Body,
                 http::Kind::Decode => Kind::Decode,
                 http::Kind::Upgrade => Kind::Upgrade,
+                http::Kind::Timeout => Kind::Timeout,
+                http::Kind::TooLarge => Kind::TooLarge,
+                http::Kind::Denied(reason) => Kind::Denied(reason),
             }),
         }
     }
@@ -283,4 +466,10 @@ pub enum Kind {
     Body,
     Decode,
     Upgrade,
+    /// The request did not complete within its timeout.
+    Timeout,
+    /// The response body exceeded the maximum response size.
+    TooLarge,
+    /// The network policy of the workflow does not allow the request, for the given reason.
+    Denied(String),
 }
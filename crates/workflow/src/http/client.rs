@@ -0,0 +1,251 @@
+use super::{call, to_method, to_request, Method, Options, Redirect, Response};
+use crate::{
+    bindings::{component::workflow::http, WorkflowError},
+    Result,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Serialize;
+use std::{fmt::Display, time::Duration};
+
+/// An HTTP client modeled on `reqwest::Client`.
+///
+/// The requests are performed by the host, so the client only holds the defaults applied to
+/// every request it builds.
+///
+/// # Examples
+///
+/// ```
+/// let client = workflow::http::Client::new();
+/// let order: Order = client
+///     .post("https://api.example.com/orders")
+///     .bearer_auth(token)
+///     .json(&new_order)
+///     .send()?
+///     .error_for_status()?
+///     .json()?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Client {
+    headers: Vec<(String, String)>,
+    timeout: Option<Duration>,
+}
+
+impl Client {
+    /// Creates a new `Client` without defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a header that is sent with every request that does not set a header with the same
+    /// name.
+    pub fn default_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets the timeout of requests that do not set their own.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Starts building a GET request to the given URL.
+    pub fn get(&self, url: impl Into<String>) -> RequestBuilder {
+        self.request(Method::GET, url)
+    }
+
+    /// Starts building a POST request to the given URL.
+    pub fn post(&self, url: impl Into<String>) -> RequestBuilder {
+        self.request(Method::POST, url)
+    }
+
+    /// Starts building a PUT request to the given URL.
+    pub fn put(&self, url: impl Into<String>) -> RequestBuilder {
+        self.request(Method::PUT, url)
+    }
+
+    /// Starts building a PATCH request to the given URL.
+    pub fn patch(&self, url: impl Into<String>) -> RequestBuilder {
+        self.request(Method::PATCH, url)
+    }
+
+    /// Starts building a DELETE request to the given URL.
+    pub fn delete(&self, url: impl Into<String>) -> RequestBuilder {
+        self.request(Method::DELETE, url)
+    }
+
+    /// Starts building a HEAD request to the given URL.
+    pub fn head(&self, url: impl Into<String>) -> RequestBuilder {
+        self.request(Method::HEAD, url)
+    }
+
+    /// Starts building a request with the given method to the given URL.
+    pub fn request(&self, method: Method, url: impl Into<String>) -> RequestBuilder {
+        RequestBuilder {
+            method,
+            url: url.into(),
+            default_headers: self.headers.clone(),
+            headers: vec![],
+            body: None,
+            options: Options {
+                timeout: self.timeout,
+                ..Options::default()
+            },
+            error: None,
+        }
+    }
+}
+
+/// A request being built, modeled on `reqwest::RequestBuilder`.
+///
+/// Errors while building the request, e.g. a body that cannot be serialized, are returned by
+/// `send`.
+#[derive(Debug)]
+pub struct RequestBuilder {
+    method: Method,
+    url: String,
+    default_headers: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+    body: Option<Vec<u8>>,
+    options: Options,
+    error: Option<WorkflowError>,
+}
+
+impl RequestBuilder {
+    /// Adds a header to the request.
+    ///
+    /// Like `reqwest`, a header with the same name is appended rather than replaced, and the
+    /// default headers of the client with that name are not sent.
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((key.into(), value.into()));
+        self
+    }
+
+    /// Adds the given query parameters to the URL.
+    pub fn query<T: Serialize + ?Sized>(mut self, query: &T) -> Self {
+        // Serializing and parsing back yields the pairs the host appends to the URL.
+        match serde_urlencoded::to_string(query)
+            .map_err(|err| err.to_string())
+            .and_then(|query| {
+                serde_urlencoded::from_str::<Vec<(String, String)>>(&query)
+                    .map_err(|err| err.to_string())
+            }) {
+            Ok(query) => self.options.query.extend(query),
+            Err(err) => self.fail(format!("invalid query: {err}")),
+        }
+        self
+    }
+
+    /// Sets the body of the request.
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets a JSON body and, unless the request already has one, the matching `Content-Type`
+    /// header.
+    pub fn json<T: Serialize + ?Sized>(mut self, json: &T) -> Self {
+        match serde_json::to_vec(json) {
+            Ok(body) => self = self.content_type("application/json").body(body),
+            Err(err) => self.fail(format!("invalid JSON body: {err}")),
+        }
+        self
+    }
+
+    /// Sets a URL encoded form body and, unless the request already has one, the matching
+    /// `Content-Type` header.
+    pub fn form<T: Serialize + ?Sized>(mut self, form: &T) -> Self {
+        match serde_urlencoded::to_string(form) {
+            Ok(body) => {
+                self = self
+                    .content_type("application/x-www-form-urlencoded")
+                    .body(body)
+            }
+            Err(err) => self.fail(format!("invalid form body: {err}")),
+        }
+        self
+    }
+
+    /// Authenticates the request with a bearer token.
+    pub fn bearer_auth(self, token: impl Display) -> Self {
+        self.header("authorization", format!("Bearer {token}"))
+    }
+
+    /// Authenticates the request with HTTP basic authentication.
+    pub fn basic_auth(self, username: impl Display, password: Option<impl Display>) -> Self {
+        let credentials = match password {
+            Some(password) => format!("{username}:{password}"),
+            None => format!("{username}:"),
+        };
+        self.header(
+            "authorization",
+            format!("Basic {}", STANDARD.encode(credentials)),
+        )
+    }
+
+    /// Sets the time after which the request fails with `Kind::Timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how redirects returned by the server are handled.
+    pub fn redirect(mut self, redirect: Redirect) -> Self {
+        self.options.redirect = Some(redirect);
+        self
+    }
+
+    /// Sets the size in bytes after which the response fails with `Kind::TooLarge`.
+    pub fn max_response_size(mut self, max_response_size: u64) -> Self {
+        self.options.max_response_size = Some(max_response_size);
+        self
+    }
+
+    /// Sends the request and waits for the response.
+    pub fn send(self) -> Result<Response> {
+        call(self.build()?)
+    }
+
+    /// Builds the request sent to the host, or returns the first error made while building it.
+    pub(crate) fn build(self) -> Result<http::Request> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        let mut headers = self.default_headers;
+        headers.retain(|(key, _)| !has_header(&self.headers, key));
+        let headers = headers
+            .into_iter()
+            .chain(self.headers)
+            .map(|(key, value)| http::Header { key, value })
+            .collect();
+        Ok(to_request(
+            to_method(self.method)?,
+            self.url,
+            headers,
+            self.body,
+            self.options,
+        ))
+    }
+
+    /// Sets the `Content-Type` header unless the request already has one.
+    fn content_type(self, content_type: &str) -> Self {
+        if has_header(&self.headers, "content-type") {
+            self
+        } else {
+            self.header("content-type", content_type)
+        }
+    }
+
+    /// Records the first error made while building the request.
+    fn fail(&mut self, error: String) {
+        self.error.get_or_insert(WorkflowError::new(None, error));
+    }
+}
+
+/// Returns whether the headers contain one with the given name.
+fn has_header(headers: &[(String, String)], key: &str) -> bool {
+    headers
+        .iter()
+        .any(|(existing, _)| existing.eq_ignore_ascii_case(key))
+}
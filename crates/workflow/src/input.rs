@@ -0,0 +1,47 @@
+use crate::{
+    bindings::{
+        self,
+        component::workflow::abi::{Content, GuestToHost, HostToGuest},
+        WorkflowError,
+    },
+    Result,
+};
+use serde::de::DeserializeOwned;
+
+/// Returns the input the workflow was started with.
+///
+/// This function makes a call to the host environment to retrieve the input payload the
+/// workflow was created with and deserializes it from JSON into the requested type.
+///
+/// # Returns
+///
+/// A `Result` containing the deserialized input, or a `WorkflowError` if the input could not
+/// be deserialized into `T`.
+///
+/// # Panics
+///
+/// This function will panic if the host returns an unexpected response format.
+///
+/// # Example
+///
+/// ```
+/// #[derive(serde::Deserialize)]
+/// struct Order {
+///     id: u64,
+/// }
+///
+/// let order = input::<Order>()?;
+/// ```
+pub fn input<T: DeserializeOwned>() -> Result<T> {
+    match bindings::call(&GuestToHost::Input) {
+        HostToGuest {
+            content: Content::Input(input),
+            ..
+        } => serde_json::from_slice(&input)
+            .map_err(|err| WorkflowError::new(None, format!("invalid input: {err}"))),
+        m => {
+            log::error!("expected Content::Input got {:?}", m);
+            unreachable!()
+        }
+    }
+}
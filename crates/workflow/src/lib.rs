@@ -1,12 +1,23 @@
 #[allow(warnings)]
 pub mod bindings;
+pub mod child;
+pub mod continuation;
 pub mod error;
 #[cfg(feature = "http")]
 pub mod http;
+pub mod input;
 pub mod logger;
 pub mod rand;
+pub mod signal;
 pub mod time;
+pub mod uuid;
 
+#[cfg(all(test, feature = "http"))]
+mod test;
+
+pub use continuation::continue_as_new;
 pub use error::Result;
+pub use input::input;
 pub use log;
+pub use serde_json;
 pub use workflow_macros::workflow;
@@ -1,5 +1,9 @@
-use crate::bindings::{self, component::workflow::abi::Level as BindingsLevel, GuestToHost};
-use log::{Level, LevelFilter, Metadata, Record, SetLoggerError};
+use crate::bindings::{
+    self,
+    component::workflow::abi::{Field, Level as BindingsLevel, LogRecord},
+    GuestToHost,
+};
+use log::{kv, Level, LevelFilter, Metadata, Record, SetLoggerError};
 
 /// A simple logger implementation.
 static LOGGER: SimpleLogger = SimpleLogger;
@@ -23,27 +27,38 @@ pub fn init(level: LevelFilter) -> Result<(), SetLoggerError> {
 impl log::Log for SimpleLogger {
     /// Checks if logging is enabled for the given metadata.
     ///
-    /// This implementation always returns true, meaning all log messages are enabled.
-    fn enabled(&self, _metadata: &Metadata) -> bool {
-        true
+    /// A record is enabled if its level is within the `LevelFilter` passed to `init`.
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
     }
 
     /// Logs a record with the appropriate level.
     ///
     /// This method translates the log levels from the `log` crate to the corresponding
-    /// levels in the `bindings` module and calls the appropriate logging function.
+    /// levels in the `bindings` module and calls the appropriate logging function. The target
+    /// and key/values of the record, e.g. `log::info!(order = 42; "shipped")`, are passed on
+    /// to the host as structured fields.
     ///
     /// # Arguments
     ///
     /// * `record` - The log record to be logged.
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
+            let mut fields = Fields(vec![]);
+            // collecting into a `Vec` cannot fail
+            let _ = record.key_values().visit(&mut fields);
+
+            let log_record = LogRecord {
+                message: record.args().to_string(),
+                target: Some(record.target().to_string()),
+                fields: fields.0,
+            };
             let bindings_level = match record.level() {
-                Level::Error => BindingsLevel::Error(record.args().to_string()),
-                Level::Warn => BindingsLevel::Warn(record.args().to_string()),
-                Level::Info => BindingsLevel::Info(record.args().to_string()),
-                Level::Debug => BindingsLevel::Debug(record.args().to_string()),
-                Level::Trace => BindingsLevel::Trace(record.args().to_string()),
+                Level::Error => BindingsLevel::Error(log_record),
+                Level::Warn => BindingsLevel::Warn(log_record),
+                Level::Info => BindingsLevel::Info(log_record),
+                Level::Debug => BindingsLevel::Debug(log_record),
+                Level::Trace => BindingsLevel::Trace(log_record),
             };
             bindings::call(&GuestToHost::Log(bindings_level));
         }
@@ -54,3 +69,16 @@ impl log::Log for SimpleLogger {
     /// This implementation does nothing as there is no buffering.
     fn flush(&self) {}
 }
+
+/// Collects the key/values of a record as `Field`s.
+struct Fields(Vec<Field>);
+
+impl<'kvs> kv::VisitSource<'kvs> for Fields {
+    fn visit_pair(&mut self, key: kv::Key<'kvs>, value: kv::Value<'kvs>) -> Result<(), kv::Error> {
+        self.0.push(Field {
+            key: key.to_string(),
+            value: value.to_string(),
+        });
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use crate::bindings::{
     self,
-    component::workflow::abi::{Content, GuestToHost, HostToGuest, Types},
+    component::workflow::abi::{Content, GuestToHost, HostToGuest, RandomType, Types},
 };
+use ::rand::distributions::uniform::{SampleRange, SampleUniform};
+pub use ::rand::{seq::SliceRandom, Rng, RngCore};
 
 /// Generates a random value.
 ///
@@ -21,6 +23,97 @@ pub fn rand<A: Rand>() -> A {
     A::rand()
 }
 
+/// Returns the random number generator of the host.
+///
+/// Every value drawn from it is journaled by the host, so any algorithm of the `rand` crate
+/// built on top of it yields the same values when the workflow is replayed.
+///
+/// # Examples
+///
+/// ```
+/// use workflow::rand::Rng;
+///
+/// let mut rng = workflow::rand::rng();
+/// let dice = rng.gen_range(1..=6);
+/// let coin = rng.gen_bool(0.5);
+/// ```
+pub fn rng() -> HostRng {
+    HostRng
+}
+
+/// Generates a random value in the given range.
+pub fn gen_range<T: SampleUniform, R: SampleRange<T>>(range: R) -> T {
+    HostRng.gen_range(range)
+}
+
+/// Generates `len` random bytes.
+///
+/// The host draws at most 1 MiB (`1 << 20` bytes) at once and fails the workflow with an
+/// error for a larger `len`, which is not retried.
+pub fn bytes(len: usize) -> Vec<u8> {
+    let mut bytes = vec![0; len];
+    HostRng.fill_bytes(&mut bytes);
+    bytes
+}
+
+/// Generates a random string of `len` ASCII letters and digits.
+///
+/// The host draws at most `1 << 20` characters at once and fails the workflow with an error
+/// for a larger `len`, which is not retried.
+pub fn alphanumeric(len: usize) -> String {
+    match call(RandomType::StringType(to_len(len))) {
+        Types::StringType(value) => value,
+        m => unexpected("Types::StringType", m),
+    }
+}
+
+/// Shuffles the elements of the slice in place.
+pub fn shuffle<T>(slice: &mut [T]) {
+    slice.shuffle(&mut HostRng)
+}
+
+/// Returns a random element of the slice, or `None` if it is empty.
+pub fn choose<T>(slice: &[T]) -> Option<&T> {
+    slice.choose(&mut HostRng)
+}
+
+/// A random number generator that draws its values from the host.
+///
+/// Each call is a host call, so prefer `fill_bytes` over drawing many small values.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HostRng;
+
+impl RngCore for HostRng {
+    fn next_u32(&mut self) -> u32 {
+        match call(RandomType::I32Type) {
+            Types::I32Type(value) => value,
+            m => unexpected("Types::I32Type", m),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match call(RandomType::U64Type) {
+            Types::U64Type(value) => value,
+            m => unexpected("Types::U64Type", m),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if dest.is_empty() {
+            return;
+        }
+        match call(RandomType::BytesType(to_len(dest.len()))) {
+            Types::BytesType(value) if value.len() == dest.len() => dest.copy_from_slice(&value),
+            m => unexpected("Types::BytesType", m),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), ::rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
 /// A trait for types that can be randomly generated.
 #[allow(unused)]
 pub trait Rand {
@@ -31,15 +124,9 @@ pub trait Rand {
 impl Rand for bool {
     /// Generates a random boolean value.
     fn rand() -> Self {
-        match bindings::call(&GuestToHost::Random(Types::BoolType(bool::default()))) {
-            HostToGuest {
-                content: Content::Value(Types::BoolType(value)),
-                ..
-            } => value,
-            m => {
-                log::error!("expected Content::Random(Types::BoolType got {:?}", m);
-                unreachable!()
-            }
+        match call(RandomType::BoolType) {
+            Types::BoolType(value) => value,
+            m => unexpected("Types::BoolType", m),
         }
     }
 }
@@ -47,31 +134,64 @@ impl Rand for bool {
 impl Rand for i32 {
     /// Generates a random 32-bit signed integer.
     fn rand() -> Self {
-        match bindings::call(&GuestToHost::Random(Types::I32Type(u32::default()))) {
-            HostToGuest {
-                content: Content::Value(Types::I32Type(value)),
-                ..
-            } => value as i32,
-            m => {
-                log::error!("expected Content::Random(Types::I32Type got {:?}", m);
-                unreachable!()
-            }
-        }
+        HostRng.next_u32() as i32
     }
 }
 
 impl Rand for f32 {
     /// Generates a random 32-bit floating-point number.
     fn rand() -> Self {
-        match bindings::call(&GuestToHost::Random(Types::F32Type(0.0))) {
-            HostToGuest {
-                content: Content::Value(Types::F32Type(value)),
-                ..
-            } => value,
-            m => {
-                log::error!("expected Content::Random(Types::F32Type got {:?}", m);
-                unreachable!()
+        match call(RandomType::F32Type) {
+            Types::F32Type(value) => value,
+            m => unexpected("Types::F32Type", m),
+        }
+    }
+}
+
+impl Rand for f64 {
+    /// Generates a random 64-bit floating-point number.
+    fn rand() -> Self {
+        match call(RandomType::F64Type) {
+            Types::F64Type(value) => value,
+            m => unexpected("Types::F64Type", m),
+        }
+    }
+}
+
+macro_rules! impl_rand {
+    ($($ty:ty),*) => {
+        $(
+            impl Rand for $ty {
+                fn rand() -> Self {
+                    HostRng.gen()
+                }
             }
+        )*
+    };
+}
+
+impl_rand!(u8, u16, u32, u64, u128, usize, i8, i16, i64, i128, isize);
+
+/// Asks the host for a random value of the given type.
+fn call(ty: RandomType) -> Types {
+    match bindings::call(&GuestToHost::Random(ty)) {
+        HostToGuest {
+            content: Content::Value(value),
+            ..
+        } => value,
+        m => {
+            log::error!("expected Content::Value got {:?}", m);
+            unreachable!()
         }
     }
 }
+
+/// Converts a length to the request, where lengths beyond `u32` are rejected by the host anyway.
+fn to_len(len: usize) -> u32 {
+    len.try_into().unwrap_or(u32::MAX)
+}
+
+fn unexpected(expected: &str, value: Types) -> ! {
+    log::error!("expected {} got {:?}", expected, value);
+    unreachable!()
+}
@@ -0,0 +1,48 @@
+use crate::{
+    bindings::{
+        self,
+        component::workflow::abi::{Content, GuestToHost, HostToGuest},
+        WorkflowError,
+    },
+    Result,
+};
+use serde::de::DeserializeOwned;
+
+/// Waits for an external signal with the given name and returns its payload.
+///
+/// If no signal has been received yet the workflow is parked by the host without holding any
+/// resources, and resumed by replay once the signal arrives. The delivered signal is journaled
+/// so replays receive the same payload at the same position.
+///
+/// # Returns
+///
+/// A `Result` containing the deserialized payload, or a `WorkflowError` if the payload could
+/// not be deserialized into `T`.
+///
+/// # Panics
+///
+/// This function will panic if the host returns an unexpected response format.
+///
+/// # Example
+///
+/// ```
+/// #[derive(serde::Deserialize)]
+/// struct Approval {
+///     approved: bool,
+/// }
+///
+/// let approval = wait_for_signal::<Approval>("approved-by-manager")?;
+/// ```
+pub fn wait_for_signal<T: DeserializeOwned>(name: impl Into<String>) -> Result<T> {
+    match bindings::call(&GuestToHost::WaitForSignal(name.into())) {
+        HostToGuest {
+            content: Content::Signal(payload),
+            ..
+        } => serde_json::from_slice(&payload)
+            .map_err(|err| WorkflowError::new(None, format!("invalid signal payload: {err}"))),
+        m => {
+            log::error!("expected Content::Signal got {:?}", m);
+            unreachable!()
+        }
+    }
+}
@@ -1,8 +1,12 @@
 use crate::bindings::{
     self,
-    component::workflow::abi::{Content, GuestToHost, HostToGuest},
+    component::workflow::abi::{self, Content, GuestToHost, HostToGuest},
+};
+use std::{
+    ops::{Add, AddAssign, Sub, SubAssign},
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Returns the current system time.
 ///
@@ -41,3 +45,218 @@ pub fn now() -> SystemTime {
         }
     }
 }
+
+/// Suspends the workflow for the given duration.
+///
+/// The wake-up deadline is journaled by the host on the first call. The workflow is parked
+/// without holding any resources and resumed by replay once the timer fires, so the timer
+/// survives restarts of the executor.
+///
+/// # Panics
+///
+/// This function will panic if the host returns an unexpected response format.
+///
+/// # Example
+///
+/// ```
+/// sleep(Duration::from_secs(60 * 60));
+/// ```
+pub fn sleep(duration: Duration) {
+    timer(GuestToHost::Sleep(abi::Duration {
+        secs: duration.as_secs(),
+        nanos: duration.subsec_nanos(),
+    }))
+}
+
+/// Suspends the workflow until the given time.
+///
+/// See [`sleep`] for how the workflow is parked and resumed.
+///
+/// # Panics
+///
+/// This function will panic if `deadline` is before the unix epoch or if the host returns an
+/// unexpected response format.
+///
+/// # Example
+///
+/// ```
+/// sleep_until(now() + Duration::from_secs(60));
+/// ```
+pub fn sleep_until(deadline: SystemTime) {
+    let duration_since_epoch = deadline
+        .duration_since(UNIX_EPOCH)
+        .expect("deadline should be after the unix epoch");
+    timer(GuestToHost::SleepUntil(abi::SystemTime {
+        tv_sec: duration_since_epoch.as_secs(),
+        tv_nsec: duration_since_epoch.subsec_nanos(),
+    }))
+}
+
+/// Internal function to wait for a durable timer.
+fn timer(request: GuestToHost) {
+    match bindings::call(&request) {
+        HostToGuest {
+            content: Content::Timer(_),
+            ..
+        } => {}
+        m => {
+            // Log an error and panic if an unexpected response is received
+            log::error!("expected Content::Timer got {:?}", m);
+            unreachable!()
+        }
+    }
+}
+
+/// Latest time read by `Instant::now`, which keeps instants from going backwards.
+static LATEST: Mutex<Option<SystemTime>> = Mutex::new(None);
+
+/// A measurement of workflow time, like `std::time::Instant`.
+///
+/// Instants are read from the journaled time of the host, so `elapsed` and `duration_since`
+/// return the same durations when the workflow is replayed. An instant is never earlier than
+/// one created before it, even if the clock of the host is set back.
+///
+/// # Example
+///
+/// ```
+/// let start = Instant::now();
+/// charge_card()?;
+/// log::info!("charged in {:?}", start.elapsed());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant(SystemTime);
+
+impl Instant {
+    /// Returns an instant corresponding to "now".
+    pub fn now() -> Self {
+        let mut latest = LATEST.lock().unwrap();
+        let now = latest.map_or(now(), |latest| latest.max(now()));
+        *latest = Some(now);
+        Self(now)
+    }
+
+    /// Returns the time elapsed from `earlier` to this instant, or zero if `earlier` is later.
+    pub fn duration_since(&self, earlier: Instant) -> Duration {
+        self.checked_duration_since(earlier).unwrap_or_default()
+    }
+
+    /// Returns the time elapsed from `earlier` to this instant, or `None` if `earlier` is later.
+    pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+        self.0.duration_since(earlier.0).ok()
+    }
+
+    /// Returns the time elapsed since this instant was created.
+    ///
+    /// Every call reads the time of the host, which is journaled like any other host call.
+    pub fn elapsed(&self) -> Duration {
+        Instant::now().duration_since(*self)
+    }
+
+    /// Returns the instant `duration` after this one, or `None` if it cannot be represented.
+    pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+        self.0.checked_add(duration).map(Instant)
+    }
+
+    /// Returns the instant `duration` before this one, or `None` if it cannot be represented.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+        self.0.checked_sub(duration).map(Instant)
+    }
+
+    /// Returns the wall-clock time of this instant, e.g. to pass it to `sleep_until`.
+    pub fn to_system_time(&self) -> SystemTime {
+        self.0
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, duration: Duration) -> Instant {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+
+    fn sub(self, duration: Duration) -> Instant {
+        self.checked_sub(duration)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl SubAssign<Duration> for Instant {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    fn sub(self, earlier: Instant) -> Duration {
+        self.duration_since(earlier)
+    }
+}
+
+/// Returns the current time as a UTC date and time.
+///
+/// # Example
+///
+/// ```
+/// let year = now_utc().year();
+/// ```
+#[cfg(feature = "chrono")]
+pub fn now_utc() -> chrono::DateTime<chrono::Utc> {
+    let duration_since_epoch = now()
+        .duration_since(UNIX_EPOCH)
+        .expect("host time should be after the unix epoch");
+    chrono::DateTime::from_timestamp(
+        duration_since_epoch.as_secs() as i64,
+        duration_since_epoch.subsec_nanos(),
+    )
+    .expect("host time should be in the range of chrono")
+}
+
+/// Returns the current time in the given time zone.
+///
+/// Any `chrono::TimeZone` works, e.g. a `FixedOffset` or a `chrono_tz::Tz`.
+///
+/// # Example
+///
+/// ```
+/// let berlin = now_in(&chrono_tz::Europe::Berlin);
+/// let business_hours = (9..17).contains(&berlin.hour());
+/// ```
+#[cfg(feature = "chrono")]
+pub fn now_in<Tz: chrono::TimeZone>(tz: &Tz) -> chrono::DateTime<Tz> {
+    now_utc().with_timezone(tz)
+}
+
+/// Returns the current date in the given time zone.
+#[cfg(feature = "chrono")]
+pub fn today_in<Tz: chrono::TimeZone>(tz: &Tz) -> chrono::NaiveDate {
+    now_in(tz).date_naive()
+}
+
+/// Suspends the workflow until the given date and time, see [`sleep_until`].
+#[cfg(feature = "chrono")]
+pub fn sleep_until_datetime<Tz: chrono::TimeZone>(deadline: &chrono::DateTime<Tz>) {
+    let utc = deadline.to_utc();
+    sleep_until(
+        UNIX_EPOCH
+            + Duration::from_secs(
+                utc.timestamp()
+                    .try_into()
+                    .expect("deadline should be after the unix epoch"),
+            )
+            + Duration::from_nanos(utc.timestamp_subsec_nanos().into()),
+    )
+}
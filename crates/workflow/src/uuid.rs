@@ -0,0 +1,43 @@
+use crate::{
+    rand::{HostRng, RngCore},
+    time,
+};
+use ::uuid::Builder;
+pub use ::uuid::Uuid;
+use std::time::UNIX_EPOCH;
+
+/// Generates a random (version 4) UUID.
+///
+/// The random bytes are drawn from the host, so the same UUID is returned when the workflow is
+/// replayed. Use it instead of `Uuid::new_v4` for idempotency keys and entity IDs.
+///
+/// # Example
+///
+/// ```
+/// let order_id = workflow::uuid::new_v4();
+/// ```
+pub fn new_v4() -> Uuid {
+    let mut bytes = [0; 16];
+    HostRng.fill_bytes(&mut bytes);
+    Builder::from_random_bytes(bytes).into_uuid()
+}
+
+/// Generates a time-ordered (version 7) UUID.
+///
+/// The timestamp is the journaled time of the host and the remaining bits are drawn from the
+/// host, so the same UUID is returned when the workflow is replayed.
+///
+/// # Example
+///
+/// ```
+/// let event_id = workflow::uuid::now_v7();
+/// ```
+pub fn now_v7() -> Uuid {
+    let millis = time::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let mut bytes = [0; 10];
+    HostRng.fill_bytes(&mut bytes);
+    Builder::from_unix_timestamp_millis(millis.try_into().unwrap_or(u64::MAX), &bytes).into_uuid()
+}
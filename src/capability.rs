@@ -0,0 +1,129 @@
+use crate::{
+    child::Children,
+    continuation::Continuation,
+    error::CapabilityError,
+    guest::{GuestToHost, HostToGuest},
+    http::Http,
+    input::Input,
+    logger::Logger,
+    rand::Random,
+    signal::Inbox,
+    time::Time,
+    timer::Timer,
+    workflow::State,
+};
+use anyhow::Result;
+use std::{collections::HashMap, fmt, future::Future, pin::Pin, sync::Arc};
+
+/// Future returned by `Capability::call`.
+pub type CapabilityFuture<'a> = Pin<Box<dyn Future<Output = Result<HostToGuest>> + Send + 'a>>;
+
+/// How the responses of a `Capability` are recorded in the journal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Journaling {
+    /// The executor journals every response and replays it instead of calling the capability
+    /// again, so the capability only runs once per request.
+    Journaled,
+    /// The capability is called on every execution, including replays. It may still journal
+    /// its responses itself through `State::retrieve_or_else`.
+    Unjournaled,
+}
+
+/// A host function that serves one kind of `GuestToHost` request.
+///
+/// Capabilities are registered by name on an `Executor`, replacing any built-in capability
+/// with the same name. The name a request is routed to is returned by `capability_name`.
+pub trait Capability: Send + Sync {
+    /// Name the capability is registered under.
+    fn name(&self) -> &str;
+
+    /// How responses of this capability are journaled.
+    fn journaling(&self) -> Journaling {
+        Journaling::Journaled
+    }
+
+    /// Produces the response to a request made by the guest.
+    ///
+    /// Returning an error traps the guest, and a `Suspend` error parks the workflow until it
+    /// can be resumed.
+    fn call<'a>(&'a self, state: &'a mut State, request: &'a GuestToHost) -> CapabilityFuture<'a>;
+}
+
+/// Returns the name of the capability a request is routed to.
+pub fn capability_name(request: &GuestToHost) -> &'static str {
+    match request {
+        GuestToHost::Input => "input",
+        GuestToHost::Random(_) => "random",
+        GuestToHost::Log(_) => "log",
+        GuestToHost::HttpRequest(_) => "http",
+        GuestToHost::Time => "time",
+        GuestToHost::Sleep(_) | GuestToHost::SleepUntil(_) => "timer",
+        GuestToHost::WaitForSignal(_) => "signal",
+        GuestToHost::StartChild(_) | GuestToHost::AwaitChild(_) => "child",
+        GuestToHost::ContinueAsNew(_) => "continue-as-new",
+    }
+}
+
+/// The capabilities available to a workflow, keyed by name.
+#[derive(Clone)]
+pub struct Capabilities {
+    capabilities: HashMap<String, Arc<dyn Capability>>,
+}
+
+impl fmt::Debug for Capabilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.capabilities.keys()).finish()
+    }
+}
+
+impl Default for Capabilities {
+    /// Creates the built-in capabilities.
+    fn default() -> Self {
+        let mut capabilities = Self {
+            capabilities: HashMap::new(),
+        };
+        capabilities.register(Input);
+        capabilities.register(Random);
+        capabilities.register(Logger::default());
+        capabilities.register(Http::default());
+        capabilities.register(Time);
+        capabilities.register(Timer);
+        capabilities.register(Inbox);
+        capabilities.register(Children);
+        capabilities.register(Continuation);
+        capabilities
+    }
+}
+
+impl Capabilities {
+    /// Registers a capability, replacing any capability with the same name.
+    pub fn register(&mut self, capability: impl Capability + 'static) {
+        self.capabilities
+            .insert(capability.name().to_owned(), Arc::new(capability));
+    }
+
+    /// Routes a request to the capability registered for it, journaling the response according
+    /// to `Capability::journaling`.
+    pub(crate) async fn call(
+        &self,
+        state: &mut State,
+        request: GuestToHost,
+    ) -> Result<HostToGuest> {
+        let name = capability_name(&request);
+        let capability =
+            self.capabilities.get(name).cloned().ok_or_else(|| {
+                CapabilityError::new(name, &request, "no capability is registered")
+            })?;
+
+        match capability.journaling() {
+            Journaling::Journaled => {
+                if let Some(message) = state.replay(&request)? {
+                    return Ok(message);
+                }
+                let message = capability.call(state, &request).await?;
+                state.record(&request, message)
+            }
+            Journaling::Unjournaled => capability.call(state, &request).await,
+        }
+    }
+}
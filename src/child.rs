@@ -0,0 +1,110 @@
+use crate::{
+    capability::{Capability, CapabilityFuture, Journaling},
+    error::{CapabilityError, Suspend},
+    guest::{component::workflow::abi::Content, GuestToHost, HostToGuest},
+    workflow::State,
+};
+use anyhow::anyhow;
+use std::sync::Mutex;
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+/// The eventual result of a child workflow started by another workflow.
+#[derive(Debug, Default)]
+pub struct Child {
+    result: Mutex<Option<std::result::Result<serde_json::Value, String>>>,
+    notify: Notify,
+}
+
+impl Child {
+    /// Records the result of the child and wakes any parent waiting for it.
+    pub fn complete(&self, result: std::result::Result<serde_json::Value, String>) {
+        *self.result.lock().unwrap() = Some(result);
+        self.notify.notify_waiters();
+    }
+
+    /// Returns the result of the child, if it has finished.
+    pub fn result(&self) -> Option<std::result::Result<serde_json::Value, String>> {
+        self.result.lock().unwrap().clone()
+    }
+
+    /// Waits until the child has finished.
+    pub async fn wait(&self) {
+        loop {
+            let notified = self.notify.notified();
+            if self.result.lock().unwrap().is_some() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// Starts child workflows and waits for their results.
+///
+/// The responses are journaled by the capability itself, so a child is forgotten by the
+/// executor only once its result has been recorded by the parent.
+pub struct Children;
+
+impl Capability for Children {
+    fn name(&self) -> &str {
+        "child"
+    }
+
+    fn journaling(&self) -> Journaling {
+        Journaling::Unjournaled
+    }
+
+    fn call<'a>(&'a self, state: &'a mut State, request: &'a GuestToHost) -> CapabilityFuture<'a> {
+        Box::pin(async move {
+            if let Some(message) = state.replay(request)? {
+                return Ok(message);
+            }
+            let executor = state
+                .executor
+                .clone()
+                .ok_or_else(|| anyhow!("child workflows can only be started by an Executor"))?;
+            // The guest would make the same invalid request again, so it is not retried.
+            let invalid = |reason: String| CapabilityError::new(self.name(), request, reason);
+            match request {
+                // The child id is journaled so replays do not launch the child again, and a child
+                // dropped from the journal by a retry is reattached.
+                GuestToHost::StartChild(child) => {
+                    let message = match state.reattach(request) {
+                        Some(message) => message,
+                        None => {
+                            let input = serde_json::from_slice(&child.input)
+                                .map_err(|err| invalid(format!("invalid child input: {err}")))?;
+                            let id = executor
+                                .start_child(&child.component_name, input)
+                                .map_err(|err| invalid(err.to_string()))?;
+                            HostToGuest {
+                                id: Uuid::new_v4().into(),
+                                content: Content::Child(id.to_string()),
+                            }
+                        }
+                    };
+                    state.record(request, message)
+                }
+                GuestToHost::AwaitChild(id) => {
+                    let id = Uuid::parse_str(id)
+                        .map_err(|err| invalid(format!("invalid child id {id}: {err}")))?;
+                    let Some(result) = executor.child(&id)?.result() else {
+                        return Err(Suspend::Child(id).into());
+                    };
+                    let message = HostToGuest {
+                        id: Uuid::new_v4().into(),
+                        content: Content::ChildResult(match result {
+                            Ok(output) => Ok(serde_json::to_vec(&output)?),
+                            Err(err) => Err(err),
+                        }),
+                    };
+                    let message = state.record(request, message)?;
+                    executor.forget_child(&id);
+                    Ok(message)
+                }
+                _ => Err(CapabilityError::new(self.name(), request, "unsupported request").into()),
+            }
+        })
+    }
+}
@@ -0,0 +1,121 @@
+use std::{
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    time::{Duration, SystemTime},
+};
+use tokio::sync::watch;
+
+/// Future returned by `Clock::sleep_until`.
+pub type SleepFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+/// The source of time of a workflow.
+///
+/// The time capability tells the guest `Clock::now`, durable timers compute and check their
+/// deadlines against it, and suspended workflows wait for their timers with
+/// `Clock::sleep_until`. Replacing the `SystemClock` lets tests control workflow time.
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> SystemTime;
+
+    /// Completes once `now` has reached the deadline.
+    fn sleep_until(&self, deadline: SystemTime) -> SleepFuture<'_>;
+}
+
+/// The wall clock of the host.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep_until(&self, deadline: SystemTime) -> SleepFuture<'_> {
+        Box::pin(async move {
+            let delay = deadline
+                .duration_since(SystemTime::now())
+                .unwrap_or_default();
+            tokio::time::sleep(delay).await;
+        })
+    }
+}
+
+/// A clock that is stopped at a fixed time.
+///
+/// Timers with a deadline after that time never fire.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedClock(pub SystemTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+
+    fn sleep_until(&self, deadline: SystemTime) -> SleepFuture<'_> {
+        Box::pin(async move {
+            if deadline > self.0 {
+                std::future::pending::<()>().await;
+            }
+        })
+    }
+}
+
+/// A virtual clock that only moves when it is advanced.
+///
+/// Share it with the workflows through an `Arc` and call `advance` to fire their timers. With
+/// `with_auto_advance`, sleeping jumps the clock to the deadline instead, so a simulation runs
+/// through days of workflow time instantly.
+#[derive(Debug)]
+pub struct ManualClock {
+    now: watch::Sender<SystemTime>,
+    auto_advance: bool,
+}
+
+impl ManualClock {
+    /// Creates a clock stopped at the given time.
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            now: watch::Sender::new(now),
+            auto_advance: false,
+        }
+    }
+
+    /// Advances the clock to the deadline of every timer a workflow waits for.
+    pub fn with_auto_advance(mut self) -> Self {
+        self.auto_advance = true;
+        self
+    }
+
+    /// Moves the clock forward by the given duration.
+    pub fn advance(&self, duration: Duration) {
+        self.now.send_modify(|now| *now += duration);
+    }
+
+    /// Sets the clock to the given time, which may also move it backwards.
+    pub fn set(&self, now: SystemTime) {
+        self.now.send_replace(now);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *self.now.borrow()
+    }
+
+    fn sleep_until(&self, deadline: SystemTime) -> SleepFuture<'_> {
+        Box::pin(async move {
+            if self.auto_advance {
+                self.now.send_if_modified(|now| {
+                    let later = deadline > *now;
+                    if later {
+                        *now = deadline;
+                    }
+                    later
+                });
+            }
+            // The sender lives as long as `self`, so waiting cannot fail.
+            _ = self.now.subscribe().wait_for(|now| *now >= deadline).await;
+        })
+    }
+}
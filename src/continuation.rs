@@ -0,0 +1,35 @@
+use crate::{
+    capability::{Capability, CapabilityFuture, Journaling},
+    error::{CapabilityError, ContinueAsNew},
+    guest::GuestToHost,
+    workflow::State,
+};
+
+/// Finishes the current run of the workflow so it can start again with a new input.
+///
+/// The request is not journaled, as the run it would be journaled in is dropped.
+pub struct Continuation;
+
+impl Capability for Continuation {
+    fn name(&self) -> &str {
+        "continue-as-new"
+    }
+
+    fn journaling(&self) -> Journaling {
+        Journaling::Unjournaled
+    }
+
+    fn call<'a>(&'a self, _state: &'a mut State, request: &'a GuestToHost) -> CapabilityFuture<'a> {
+        Box::pin(async move {
+            match request {
+                GuestToHost::ContinueAsNew(input) => Err(ContinueAsNew {
+                    input: serde_json::from_slice(input).map_err(|err| {
+                        CapabilityError::new(self.name(), request, format!("invalid input: {err}"))
+                    })?,
+                }
+                .into()),
+                _ => Err(CapabilityError::new(self.name(), request, "unsupported request").into()),
+            }
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use std::time::Duration;
+use wasmtime::{Config, Engine};
+
+/// Interval at which the epoch of an `Engine` is incremented, which bounds the precision of
+/// `Limits::timeout`.
+pub const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+/// Creates an `Engine` configured to run workflows.
+///
+/// A background thread increments the epoch of the engine every `EPOCH_TICK` so wall-clock
+/// timeouts can be enforced. The thread stops once the engine is dropped.
+pub fn new() -> Result<Engine> {
+    // Enable component model (which isn't supported by default)
+    let mut config = Config::new();
+    config.wasm_component_model(true);
+    config.async_support(true);
+    config.consume_fuel(true);
+    config.epoch_interruption(true);
+
+    // Create a wasmtime execution context
+    let engine = Engine::new(&config)?;
+
+    let weak = engine.weak();
+    std::thread::spawn(move || loop {
+        std::thread::sleep(EPOCH_TICK);
+        match weak.upgrade() {
+            Some(engine) => engine.increment_epoch(),
+            None => break,
+        }
+    });
+
+    Ok(engine)
+}
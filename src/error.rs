@@ -0,0 +1,162 @@
+use crate::guest::GuestToHost;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    time::{Duration, SystemTime},
+};
+use uuid::Uuid;
+
+/// Raised when a replayed workflow makes a different host call than the one recorded in the
+/// journal at the same position, typically because the workflow binary has changed.
+#[derive(Clone, Debug)]
+pub struct NonDeterminismError {
+    /// Position in the execution log where the mismatch occurred.
+    pub position: usize,
+    /// The call recorded in the journal.
+    pub expected: GuestToHost,
+    /// The call the guest actually made.
+    pub actual: GuestToHost,
+}
+
+impl fmt::Display for NonDeterminismError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "nondeterminism detected at position {}: expected {:?} but got {:?}",
+            self.position, self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for NonDeterminismError {}
+
+/// Raised when a capability cannot serve a request made by the guest.
+///
+/// The guest is trapped and the reason is recorded as the outcome of the execution. As the
+/// same request would fail again, executions failing with this error are not retried.
+#[derive(Clone, Debug)]
+pub struct CapabilityError {
+    /// Name of the capability the request was routed to.
+    pub capability: String,
+    /// The request the guest made.
+    pub request: GuestToHost,
+    /// Why the request could not be served.
+    pub reason: String,
+}
+
+impl CapabilityError {
+    pub fn new(capability: &str, request: &GuestToHost, reason: impl Into<String>) -> Self {
+        Self {
+            capability: capability.to_owned(),
+            request: request.to_owned(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for CapabilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "capability {} cannot serve {:?}: {}",
+            self.capability, self.request, self.reason
+        )
+    }
+}
+
+impl std::error::Error for CapabilityError {}
+
+/// Raised when a workflow fails on an HTTP request that was denied by its `NetworkPolicy`.
+///
+/// The error of the guest is kept as the source. As the request would be denied again,
+/// executions failing with this error are not retried.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Denied {
+    /// URL of the denied request.
+    pub url: Option<String>,
+    /// Why the request was denied.
+    pub reason: String,
+}
+
+impl fmt::Display for Denied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.url {
+            Some(url) => write!(f, "request to {} denied: {}", url, self.reason),
+            None => write!(f, "request denied: {}", self.reason),
+        }
+    }
+}
+
+impl std::error::Error for Denied {}
+
+/// Raised when the guest finishes its current run to start again with a new input.
+///
+/// The journal of the workflow is dropped and the next execution runs with `input`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContinueAsNew {
+    /// Input of the new run.
+    pub input: serde_json::Value,
+}
+
+impl fmt::Display for ContinueAsNew {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "workflow continued as new with input {}", self.input)
+    }
+}
+
+impl std::error::Error for ContinueAsNew {}
+
+/// Raised by a capability to park the workflow until it is able to make progress.
+///
+/// The wasmtime `Store` is dropped and the workflow is resumed later by replaying its journal.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Suspend {
+    /// Waiting for a durable timer that fires at the given time.
+    Timer(SystemTime),
+    /// Waiting for an external signal with the given name.
+    Signal(String),
+    /// Waiting for the child workflow with the given id to finish.
+    Child(Uuid),
+}
+
+impl fmt::Display for Suspend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Suspend::Timer(deadline) => write!(f, "suspended until {:?}", deadline),
+            Suspend::Signal(name) => write!(f, "suspended waiting for signal {:?}", name),
+            Suspend::Child(id) => write!(f, "suspended waiting for child workflow {}", id),
+        }
+    }
+}
+
+impl std::error::Error for Suspend {}
+
+/// Raised when an execution exceeds one of the `Limits` configured on its workflow.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ResourceExhausted {
+    /// The execution consumed all of its fuel.
+    Fuel(u64),
+    /// A linear memory tried to grow beyond the given number of bytes.
+    Memory(usize),
+    /// A table tried to grow beyond the given number of elements.
+    Table(u32),
+    /// The execution ran for longer than the given wall-clock time.
+    Timeout(Duration),
+}
+
+impl fmt::Display for ResourceExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceExhausted::Fuel(fuel) => write!(f, "fuel budget of {} exhausted", fuel),
+            ResourceExhausted::Memory(bytes) => {
+                write!(f, "memory limit of {} bytes exceeded", bytes)
+            }
+            ResourceExhausted::Table(elements) => {
+                write!(f, "table limit of {} elements exceeded", elements)
+            }
+            ResourceExhausted::Timeout(timeout) => write!(f, "timed out after {:?}", timeout),
+        }
+    }
+}
+
+impl std::error::Error for ResourceExhausted {}
@@ -0,0 +1,266 @@
+use crate::{
+    capability::{Capabilities, Capability},
+    child::Child,
+    clock::{Clock, SystemClock},
+    http::HttpConfig,
+    journal::JournalStore,
+    limits::Limits,
+    policy::NetworkPolicy,
+    registry::ComponentRegistry,
+    signal::{Signal, Signals},
+    workflow::{State, Workflow},
+};
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, Weak},
+};
+use uuid::Uuid;
+use wasmtime::Engine;
+
+/// Creates workflows on a shared `Engine` and routes external events to them.
+#[derive(Clone)]
+pub struct Executor {
+    registry: ComponentRegistry,
+    journal: Option<Arc<dyn JournalStore>>,
+    limits: Limits,
+    capabilities: Capabilities,
+    network_policy: Option<Arc<NetworkPolicy>>,
+    component_network_policies: HashMap<String, Arc<NetworkPolicy>>,
+    clock: Arc<dyn Clock>,
+    signals: Arc<Mutex<HashMap<Uuid, Weak<Signals>>>>,
+    components: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    children: Arc<Mutex<HashMap<Uuid, Arc<Child>>>>,
+}
+
+impl fmt::Debug for Executor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Executor")
+            .field("journal", &self.journal)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Executor {
+    pub fn new(engine: &Engine) -> Self {
+        Self {
+            registry: ComponentRegistry::new(engine),
+            journal: None,
+            limits: Limits::default(),
+            capabilities: Capabilities::default(),
+            network_policy: None,
+            component_network_policies: HashMap::new(),
+            clock: Arc::new(SystemClock),
+            signals: Arc::new(Mutex::new(HashMap::new())),
+            components: Arc::new(Mutex::new(HashMap::new())),
+            children: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Persists the journal of every workflow created by this executor to the given store.
+    pub fn with_journal(mut self, journal: Arc<dyn JournalStore>) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// Uses the given `ComponentRegistry` to compile and cache the components of all workflows.
+    pub fn with_registry(mut self, registry: ComponentRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Applies the given `Limits` to every workflow created by this executor.
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Registers a host capability for every workflow created by this executor, replacing the
+    /// built-in capability with the same name.
+    pub fn with_capability(mut self, capability: impl Capability + 'static) -> Self {
+        self.capabilities.register(capability);
+        self
+    }
+
+    /// Sends the HTTP requests of all workflows through one client built from the given config.
+    pub fn with_http(self, config: &HttpConfig) -> Result<Self> {
+        Ok(self.with_capability(config.build()?))
+    }
+
+    /// Restricts the endpoints every workflow created by this executor may reach over HTTP.
+    pub fn with_network_policy(mut self, policy: NetworkPolicy) -> Self {
+        self.network_policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Restricts the endpoints child workflows of the named component may reach over HTTP,
+    /// taking precedence over the policy set with `with_network_policy`.
+    pub fn with_component_network_policy(
+        mut self,
+        name: impl Into<String>,
+        policy: NetworkPolicy,
+    ) -> Self {
+        self.component_network_policies
+            .insert(name.into(), Arc::new(policy));
+        self
+    }
+
+    /// Uses the given `Clock` as the source of time of every workflow created by this executor.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Registers a component binary under a name so workflows can start it as a child.
+    pub fn register(&self, name: impl Into<String>, binary: Vec<u8>) {
+        self.components.lock().unwrap().insert(name.into(), binary);
+    }
+
+    /// Creates a new `Workflow` for the given component binary.
+    pub fn workflow(&self, binary: &Vec<u8>) -> Workflow {
+        self.attach(State::default(), binary)
+    }
+
+    /// Reconstructs a `Workflow` from the journal by the `State::id` it was persisted with.
+    pub fn load(&self, binary: &Vec<u8>, id: &Uuid) -> Result<Workflow> {
+        let state = self.load_state(id)?;
+        Ok(self.attach(state, binary))
+    }
+
+    /// Starts a registered component as a child workflow and returns its `State::id`.
+    ///
+    /// The child runs to completion in the background with its own `State`.
+    pub fn start_child(&self, name: &str, input: serde_json::Value) -> Result<Uuid> {
+        let binary = self.component(name)?;
+        let mut state = State::default().with_input(input);
+        state.component = Some(name.to_owned());
+        let workflow = self.attach(state, &binary);
+        Ok(self.spawn_child(workflow))
+    }
+
+    /// Returns the child workflow with the given id, resuming it from the journal if it is not
+    /// running in this process.
+    ///
+    /// A child that has finished, successfully or not, is not run again.
+    pub fn child(&self, id: &Uuid) -> Result<Arc<Child>> {
+        if let Some(child) = self.children.lock().unwrap().get(id) {
+            return Ok(child.clone());
+        }
+
+        let state = self.load_state(id)?;
+        let result = match (state.output(), state.error()) {
+            (Some(output), _) => Some(Ok(output.to_owned())),
+            (None, Some(error)) => Some(Err(error.to_owned())),
+            (None, None) => None,
+        };
+        if let Some(result) = result {
+            let child = Arc::new(Child::default());
+            child.complete(result);
+            self.children.lock().unwrap().insert(*id, child.clone());
+            return Ok(child);
+        }
+
+        let name = state
+            .component
+            .clone()
+            .ok_or_else(|| anyhow!("workflow {id} was not started as a child"))?;
+        let workflow = self.attach(state, &self.component(&name)?);
+        self.spawn_child(workflow);
+        self.child(id)
+    }
+
+    /// Forgets a finished child once its parent has journaled the result, which is replayed
+    /// from the journal from then on.
+    pub(crate) fn forget_child(&self, id: &Uuid) {
+        self.children.lock().unwrap().remove(id);
+    }
+
+    /// Sends a signal to a workflow.
+    ///
+    /// The signal is journaled and delivered the next time the workflow waits for a signal
+    /// with this name, waking the workflow if it is parked.
+    pub fn signal<T: Serialize>(&self, workflow_id: &Uuid, name: &str, payload: &T) -> Result<()> {
+        let signal = Signal::new(name, serde_json::to_vec(payload)?);
+
+        let signals = self
+            .signals
+            .lock()
+            .unwrap()
+            .get(workflow_id)
+            .and_then(Weak::upgrade);
+
+        if let Some(journal) = &self.journal {
+            journal.save_signal(workflow_id, &signal)?;
+        } else if signals.is_none() {
+            return Err(anyhow!("workflow {workflow_id} is not running"));
+        }
+
+        if let Some(signals) = signals {
+            signals.push(signal);
+        }
+
+        Ok(())
+    }
+
+    /// Binds a `State` to this executor and wraps it in a `Workflow`.
+    fn attach(&self, mut state: State, binary: &Vec<u8>) -> Workflow {
+        if let Some(journal) = &self.journal {
+            state = state.with_journal(journal.clone());
+        }
+        state.executor = Some(self.clone());
+        state.capabilities = self.capabilities.clone();
+        state.clock = self.clock.clone();
+        state.network_policy = state
+            .component
+            .as_ref()
+            .and_then(|name| self.component_network_policies.get(name))
+            .or(self.network_policy.as_ref())
+            .cloned();
+
+        // track the signal inbox of the workflow so it can be reached by `signal`
+        let mut signals = self.signals.lock().unwrap();
+        signals.retain(|_, inbox| inbox.strong_count() > 0);
+        signals.insert(state.id, Arc::downgrade(&state.signals));
+
+        let mut workflow = Workflow::new(&self.registry, binary);
+        workflow.with_state(state).with_limits(self.limits.clone());
+        workflow
+    }
+
+    fn load_state(&self, id: &Uuid) -> Result<State> {
+        self.journal
+            .as_ref()
+            .ok_or_else(|| anyhow!("executor has no journal to load workflow {id} from"))?
+            .load_state(id)?
+            .ok_or_else(|| anyhow!("workflow state {id} not found in journal"))
+    }
+
+    fn component(&self, name: &str) -> Result<Vec<u8>> {
+        self.components
+            .lock()
+            .unwrap()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| anyhow!("component {name} is not registered"))
+    }
+
+    /// Runs a child workflow in the background and returns its id.
+    fn spawn_child(&self, mut workflow: Workflow) -> Uuid {
+        let id = workflow.state().id;
+        let child = Arc::new(Child::default());
+        self.children.lock().unwrap().insert(id, child.clone());
+
+        tokio::spawn(async move {
+            child.complete(
+                workflow
+                    .run_to_completion()
+                    .await
+                    .map_err(|err| err.root_cause().to_string()),
+            );
+        });
+
+        id
+    }
+}
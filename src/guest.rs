@@ -1,11 +1,12 @@
-use crate::{http, logger, rand, time, workflow::State};
+use crate::workflow::State;
 
 wasmtime::component::bindgen!({
     world: "workflow",
     path: "crates/workflow/wit/world.wit",
-    trappable_imports: false,
+    trappable_imports: true,
     async: true,
     additional_derives: [
+        PartialEq,
         serde::Deserialize,
         serde::Serialize,
     ],
@@ -18,20 +19,19 @@ impl WorkflowImports for State {
         &'life0 mut self,
         request: GuestToHost,
     ) -> ::core::pin::Pin<
-        Box<dyn ::core::future::Future<Output = HostToGuest> + ::core::marker::Send + 'async_trait>,
+        Box<
+            dyn ::core::future::Future<Output = wasmtime::Result<HostToGuest>>
+                + ::core::marker::Send
+                + 'async_trait,
+        >,
     >
     where
         'life0: 'async_trait,
         Self: 'async_trait,
     {
         Box::pin(async move {
-            match request {
-                GuestToHost::Input => todo!(),
-                GuestToHost::Random(_) => rand::call(self, request).await.unwrap(),
-                GuestToHost::Log(_) => logger::call(self, request).await.unwrap(),
-                GuestToHost::HttpRequest(_) => http::call(self, request).await.unwrap(),
-                GuestToHost::Time => time::call(self, request).await.unwrap(),
-            }
+            let capabilities = self.capabilities.clone();
+            capabilities.call(self, request).await
         })
     }
 }
@@ -1,99 +1,495 @@
-use std::str::FromStr;
-
-use anyhow::Result;
-use reqwest::header::{HeaderMap, HeaderName};
+use reqwest::{
+    dns::{Addrs, Name, Resolve, Resolving},
+    StatusCode,
+};
+use std::{
+    fmt,
+    sync::{Arc, Mutex, Weak},
+    time::{Duration, Instant},
+};
 use uuid::Uuid;
 
 use crate::{
+    capability::{Capability, CapabilityFuture},
+    error::CapabilityError,
     guest::{
         component::workflow::{
             abi::Content,
-            http::{Error, Header, Kind, Method, Response, Version},
+            http::{Error, Header, Kind, Method, RedirectPolicy, Request, Response, Version},
         },
         GuestToHost, HostToGuest,
     },
+    policy::NetworkPolicy,
     workflow::State,
 };
 
-pub async fn call(state: &mut State, request: GuestToHost) -> Result<HostToGuest> {
-    match request {
-        GuestToHost::HttpRequest(request) => {
-            state
-                .retrieve_or_else(|| {
-                    async {
-                        let client = reqwest::Client::new();
-
-                        let mut headers = HeaderMap::with_capacity(request.headers.len());
-                        for header in &request.headers {
-                            headers
-                                .insert(HeaderName::from_str(&header.key)?, header.value.parse()?);
-                        }
-
-                        // Build the request.
-                        let mut req = match request.method {
-                            Method::Get => client.get(&request.path).headers(headers),
-                            Method::Post => client.post(&request.path).headers(headers),
-                            Method::Delete => client.delete(&request.path).headers(headers),
-                        };
-
-                        if let Some(body) = request.body.clone() {
-                            req = req.body(body);
-                        }
-
-                        // Execute the request.
-                        Ok(HostToGuest {
-                            id: Uuid::new_v4().into(),
-                            content: Content::HttpResponse(match req.send().await {
-                                Ok(resp) => Ok(Response {
-                                    status: resp.status().as_u16(),
-                                    http_version: match resp.version() {
-                                        reqwest::Version::HTTP_09 => Version::HttpZeroNine,
-                                        reqwest::Version::HTTP_10 => Version::HttpOneZero,
-                                        reqwest::Version::HTTP_11 => Version::HttpOneOne,
-                                        reqwest::Version::HTTP_2 => Version::HttpTwoZero,
-                                        reqwest::Version::HTTP_3 => Version::HttpThreeZero,
-                                        _ => unimplemented!(),
-                                    },
-                                    headers: resp
-                                        .headers()
-                                        .iter()
-                                        .map(|(header_name, header_value)| {
-                                            Ok(Header {
-                                                key: header_name.to_string(),
-                                                value: header_value
-                                                    .to_str()
-                                                    .map(|s| s.to_string())?,
-                                            })
-                                        })
-                                        .collect::<Result<Vec<_>>>()?,
-                                    content_length: resp.content_length(),
-                                    url: resp.url().to_string(),
-                                    body: resp.bytes().await?.to_vec(),
-                                }),
-                                Err(err) => Err(Error {
-                                    url: err.url().map(|url| url.to_string()),
-                                    kind: if err.is_builder() {
-                                        Some(Kind::Builder)
-                                    } else if err.is_request() {
-                                        Some(Kind::Request)
-                                    } else if err.is_body() {
-                                        Some(Kind::Body)
-                                    } else if err.is_decode() {
-                                        Some(Kind::Decode)
-                                    } else if err.is_redirect() {
-                                        Some(Kind::Redirect)
-                                    } else if err.is_status() {
-                                        Some(Kind::Status(err.status().unwrap().as_u16()))
-                                    } else {
-                                        None
-                                    },
-                                }),
-                            }),
-                        })
-                    }
-                })
+/// Performs HTTP requests on behalf of the guest.
+///
+/// All requests share one `reqwest::Client`, and with it its connection pool, so an `Http`
+/// should be created once and registered on the `Executor` running the workflows. Workflows
+/// whose `NetworkPolicy` restricts networks share a client per policy instead, whose resolver
+/// only returns allowed addresses, so a denied address is never connected to.
+///
+/// Every failure, including invalid requests built by the guest, is delivered to the guest as
+/// an `Error` rather than trapping it.
+#[derive(Clone, Debug)]
+pub struct Http {
+    config: HttpConfig,
+    client: reqwest::Client,
+    /// Clients of the live network policies that restrict networks.
+    restricted: Arc<Mutex<Vec<RestrictedClient>>>,
+}
+
+/// A client whose resolver enforces the networks of a policy, kept while the policy is alive.
+type RestrictedClient = (Weak<NetworkPolicy>, reqwest::Client);
+
+impl Default for Http {
+    /// Creates an `Http` capability with the default `HttpConfig`.
+    fn default() -> Self {
+        HttpConfig::default()
+            .build()
+            .expect("default HTTP client should build")
+    }
+}
+
+impl Http {
+    /// Returns the client to send the requests of a workflow with the given policy.
+    pub(crate) fn client(
+        &self,
+        policy: Option<&Arc<NetworkPolicy>>,
+    ) -> anyhow::Result<reqwest::Client> {
+        let Some(policy) = policy.filter(|policy| {
+            !policy.allowed_networks.is_empty() || !policy.denied_networks.is_empty()
+        }) else {
+            return Ok(self.client.clone());
+        };
+
+        let mut restricted = self.restricted.lock().unwrap();
+        restricted.retain(|(policy, _)| policy.strong_count() > 0);
+        if let Some((_, client)) = restricted
+            .iter()
+            .find(|(restricted, _)| restricted.as_ptr() == Arc::as_ptr(policy))
+        {
+            return Ok(client.clone());
+        }
+
+        let client = self
+            .config
+            .builder()?
+            .dns_resolver(Arc::new(PolicyResolver(policy.clone())))
+            .build()?;
+        restricted.push((Arc::downgrade(policy), client.clone()));
+        Ok(client)
+    }
+}
+
+/// Settings of the client shared by all HTTP requests of the guests.
+#[derive(Clone, Debug, Default)]
+pub struct HttpConfig {
+    /// Additional PEM encoded root certificates to trust.
+    pub root_certificates: Vec<Vec<u8>>,
+    /// PEM encoded client certificate and PKCS #8 private key to authenticate with.
+    pub identity: Option<(Vec<u8>, Vec<u8>)>,
+    /// URL of a proxy all requests are sent through.
+    ///
+    /// The networks of a `NetworkPolicy` then also apply to the address of the proxy.
+    pub proxy: Option<String>,
+    /// `User-Agent` header sent with every request.
+    pub user_agent: Option<String>,
+    /// Headers sent with every request unless the guest sets them.
+    pub default_headers: Vec<(String, String)>,
+    /// Timeout of requests that do not set their own.
+    pub timeout: Option<Duration>,
+    /// Timeout for establishing a connection.
+    pub connect_timeout: Option<Duration>,
+    /// Maximum number of idle connections kept open per host.
+    pub pool_max_idle_per_host: Option<usize>,
+}
+
+impl HttpConfig {
+    pub fn with_root_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    pub fn with_identity(
+        mut self,
+        certificate: impl Into<Vec<u8>>,
+        key: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.identity = Some((certificate.into(), key.into()));
+        self
+    }
+
+    pub fn with_proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn with_default_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_headers.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    pub fn with_pool_max_idle_per_host(mut self, pool_max_idle_per_host: usize) -> Self {
+        self.pool_max_idle_per_host = Some(pool_max_idle_per_host);
+        self
+    }
+
+    /// Builds the shared client and wraps it in an `Http` capability.
+    pub fn build(&self) -> anyhow::Result<Http> {
+        Ok(Http {
+            config: self.clone(),
+            client: self.builder()?.build()?,
+            restricted: Arc::default(),
+        })
+    }
+
+    fn builder(&self) -> anyhow::Result<reqwest::ClientBuilder> {
+        // Redirects are followed by the capability so the policy can vary per request.
+        let mut builder = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none());
+
+        for pem in &self.root_certificates {
+            builder = builder.add_root_certificate(reqwest::Certificate::from_pem(pem)?);
+        }
+        if let Some((certificate, key)) = &self.identity {
+            builder = builder.identity(reqwest::Identity::from_pkcs8_pem(certificate, key)?);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        let mut headers = reqwest::header::HeaderMap::new();
+        for (key, value) in &self.default_headers {
+            headers.insert(
+                reqwest::header::HeaderName::from_bytes(key.as_bytes())?,
+                reqwest::header::HeaderValue::from_str(value)?,
+            );
+        }
+        builder = builder.default_headers(headers);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(pool_max_idle_per_host) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(pool_max_idle_per_host);
+        }
+
+        Ok(builder)
+    }
+}
+
+/// Resolves host names to the addresses the `NetworkPolicy` allows.
+///
+/// A host that resolves to a denied address fails to resolve with an `AddressDenied` error, so
+/// the policy also holds if the host resolves differently than when the request was checked.
+struct PolicyResolver(Arc<NetworkPolicy>);
+
+impl Resolve for PolicyResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let policy = self.0.clone();
+        Box::pin(async move {
+            let addresses = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .collect::<Vec<_>>();
+            for address in &addresses {
+                policy.check_address(address.ip()).map_err(AddressDenied)?;
+            }
+            Ok(Box::new(addresses.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Raised by `PolicyResolver` when a host resolves to an address the policy denies.
+#[derive(Debug)]
+pub(crate) struct AddressDenied(pub(crate) String);
+
+impl fmt::Display for AddressDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for AddressDenied {}
+
+impl Capability for Http {
+    fn name(&self) -> &str {
+        "http"
+    }
+
+    fn call<'a>(&'a self, state: &'a mut State, request: &'a GuestToHost) -> CapabilityFuture<'a> {
+        Box::pin(async move {
+            match request {
+                // Denied requests are journaled like any other response.
+                GuestToHost::HttpRequest(http_request) => {
+                    let client = self.client(state.network_policy.as_ref())?;
+                    Ok(HostToGuest {
+                        id: Uuid::new_v4().into(),
+                        content: Content::HttpResponse(
+                            send(&client, state.network_policy.as_deref(), http_request).await,
+                        ),
+                    })
+                }
+                _ => Err(CapabilityError::new(self.name(), request, "unsupported request").into()),
+            }
+        })
+    }
+}
+
+/// Number of redirects followed when the request does not set a redirect policy.
+const DEFAULT_MAX_REDIRECTS: u32 = 10;
+
+async fn send(
+    client: &reqwest::Client,
+    policy: Option<&NetworkPolicy>,
+    http_request: &Request,
+) -> Result<Response, Error> {
+    let max_redirects = match http_request.redirect {
+        Some(RedirectPolicy::None) => 0,
+        Some(RedirectPolicy::Limited(max)) => max,
+        None => DEFAULT_MAX_REDIRECTS,
+    };
+
+    let mut method = match http_request.method {
+        Method::Get => reqwest::Method::GET,
+        Method::Post => reqwest::Method::POST,
+        Method::Put => reqwest::Method::PUT,
+        Method::Patch => reqwest::Method::PATCH,
+        Method::Delete => reqwest::Method::DELETE,
+        Method::Head => reqwest::Method::HEAD,
+        Method::Options => reqwest::Method::OPTIONS,
+    };
+    let mut url = reqwest::Url::parse(&http_request.path).map_err(|_| Error {
+        url: Some(http_request.path.clone()),
+        kind: Some(Kind::Builder),
+    })?;
+    let origin = url.origin();
+    let mut body = http_request.body.clone();
+    let mut redirects = 0;
+    // The timeout covers the whole exchange, including every redirect and the response body.
+    let deadline = http_request
+        .timeout_ms
+        .map(|timeout| Instant::now() + Duration::from_millis(timeout));
+
+    let denied = |url: &reqwest::Url, reason: String| Error {
+        url: Some(url.to_string()),
+        kind: Some(Kind::Denied(reason)),
+    };
+
+    let resp = loop {
+        // Every hop is checked, so a redirect cannot lead to a forbidden endpoint.
+        if let Some(policy) = policy {
+            policy
+                .check(&method, &url)
                 .await
+                .map_err(|reason| denied(&url, reason))?;
+        }
+
+        // Build the request, invalid headers are reported by `send` as a builder error.
+        let mut req = client.request(method.clone(), url.clone());
+        for header in &http_request.headers {
+            // Credentials are not forwarded when a redirect leaves the original origin.
+            if url.origin() != origin && is_sensitive(&header.key) {
+                continue;
+            }
+            // The headers describing the body are dropped along with it.
+            if body.is_none() && http_request.body.is_some() && is_content(&header.key) {
+                continue;
+            }
+            req = req.header(&header.key, &header.value);
+        }
+        if redirects == 0 && !http_request.query.is_empty() {
+            let query = http_request
+                .query
+                .iter()
+                .map(|param| (&param.key, &param.value))
+                .collect::<Vec<_>>();
+            req = req.query(&query);
+        }
+        if let Some(deadline) = deadline {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Error {
+                    url: Some(url.to_string()),
+                    kind: Some(Kind::Timeout),
+                });
+            }
+            req = req.timeout(remaining);
+        }
+        if let Some(body) = body.clone() {
+            req = req.body(body);
+        }
+
+        // Execute the request, where the resolver of the client enforces the networks.
+        let resp = req.send().await.map_err(|err| match address_denied(&err) {
+            Some(reason) => denied(&url, reason),
+            None => to_error(err),
+        })?;
+
+        let location = resp
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| resp.url().join(location).ok());
+        match location {
+            Some(location) if resp.status().is_redirection() && max_redirects > 0 => {
+                redirects += 1;
+                if redirects > max_redirects {
+                    return Err(Error {
+                        url: Some(resp.url().to_string()),
+                        kind: Some(Kind::Redirect),
+                    });
+                }
+
+                // Like reqwest, a 303 switches every method but HEAD to GET, and a 301 or 302
+                // switches a POST, both without the body.
+                let status = resp.status();
+                if status == StatusCode::SEE_OTHER
+                    || (status != StatusCode::TEMPORARY_REDIRECT
+                        && status != StatusCode::PERMANENT_REDIRECT
+                        && method == reqwest::Method::POST)
+                {
+                    if method != reqwest::Method::HEAD {
+                        method = reqwest::Method::GET;
+                    }
+                    body = None;
+                }
+                url = location;
+            }
+            _ => break resp,
+        }
+    };
+
+    let too_large = |url: &reqwest::Url| Error {
+        url: Some(url.to_string()),
+        kind: Some(Kind::TooLarge),
+    };
+    if let (Some(max), Some(length)) = (http_request.max_response_size, resp.content_length()) {
+        if length > max {
+            return Err(too_large(resp.url()));
+        }
+    }
+
+    let status = resp.status().as_u16();
+    let http_version = match resp.version() {
+        reqwest::Version::HTTP_09 => Version::HttpZeroNine,
+        reqwest::Version::HTTP_10 => Version::HttpOneZero,
+        reqwest::Version::HTTP_11 => Version::HttpOneOne,
+        reqwest::Version::HTTP_2 => Version::HttpTwoZero,
+        reqwest::Version::HTTP_3 => Version::HttpThreeZero,
+        _ => {
+            return Err(Error {
+                url: Some(resp.url().to_string()),
+                kind: Some(Kind::Decode),
+            })
+        }
+    };
+    let headers = resp
+        .headers()
+        .iter()
+        .map(|(header_name, header_value)| Header {
+            key: header_name.to_string(),
+            value: String::from_utf8_lossy(header_value.as_bytes()).into_owned(),
+        })
+        .collect();
+    let content_length = resp.content_length();
+    let url = resp.url().clone();
+
+    // Read the body in chunks so an oversized response is aborted early.
+    let mut resp = resp;
+    let mut body = vec![];
+    while let Some(chunk) = resp.chunk().await.map_err(to_error)? {
+        body.extend_from_slice(&chunk);
+        if http_request
+            .max_response_size
+            .is_some_and(|max| body.len() as u64 > max)
+        {
+            return Err(too_large(&url));
+        }
+    }
+
+    Ok(Response {
+        status,
+        http_version,
+        headers,
+        content_length,
+        url: url.to_string(),
+        body,
+    })
+}
+
+/// Returns whether a header carries credentials.
+fn is_sensitive(name: &str) -> bool {
+    [
+        "authorization",
+        "cookie",
+        "proxy-authorization",
+        "www-authenticate",
+    ]
+    .iter()
+    .any(|sensitive| name.eq_ignore_ascii_case(sensitive))
+}
+
+/// Returns whether a header describes the body of a request.
+fn is_content(name: &str) -> bool {
+    [
+        "content-encoding",
+        "content-length",
+        "content-type",
+        "transfer-encoding",
+    ]
+    .iter()
+    .any(|content| name.eq_ignore_ascii_case(content))
+}
+
+/// Returns the reason the resolver denied the address a request would connect to.
+pub(crate) fn address_denied(err: &(dyn std::error::Error + 'static)) -> Option<String> {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if let Some(AddressDenied(reason)) = err.downcast_ref() {
+            return Some(reason.clone());
         }
-        _ => unreachable!(),
+        source = err.source();
+    }
+    None
+}
+
+fn to_error(err: reqwest::Error) -> Error {
+    Error {
+        url: err.url().map(|url| url.to_string()),
+        kind: if err.is_timeout() {
+            Some(Kind::Timeout)
+        } else if err.is_builder() {
+            Some(Kind::Builder)
+        } else if err.is_request() {
+            Some(Kind::Request)
+        } else if err.is_body() {
+            Some(Kind::Body)
+        } else if err.is_decode() {
+            Some(Kind::Decode)
+        } else if err.is_redirect() {
+            Some(Kind::Redirect)
+        } else {
+            err.status().map(|status| Kind::Status(status.as_u16()))
+        },
     }
 }
@@ -0,0 +1,28 @@
+use crate::{
+    capability::{Capability, CapabilityFuture},
+    error::CapabilityError,
+    guest::{component::workflow::abi::Content, GuestToHost, HostToGuest},
+    workflow::State,
+};
+use uuid::Uuid;
+
+/// Delivers the input the workflow was started with to the guest.
+pub struct Input;
+
+impl Capability for Input {
+    fn name(&self) -> &str {
+        "input"
+    }
+
+    fn call<'a>(&'a self, state: &'a mut State, request: &'a GuestToHost) -> CapabilityFuture<'a> {
+        Box::pin(async move {
+            match request {
+                GuestToHost::Input => Ok(HostToGuest {
+                    id: Uuid::new_v4().into(),
+                    content: Content::Input(serde_json::to_vec(&state.input)?),
+                }),
+                _ => Err(CapabilityError::new(self.name(), request, "unsupported request").into()),
+            }
+        })
+    }
+}
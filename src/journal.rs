@@ -0,0 +1,523 @@
+use crate::{
+    guest::{component::workflow::abi::Content, HostToGuest},
+    signal::Signal,
+    workflow::{Execution, LogMessage, Outcome, State},
+};
+use anyhow::{anyhow, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    path::Path,
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use uuid::Uuid;
+
+/// A durable store for workflow journals.
+///
+/// Implementations persist the `State`, its `Execution`s and every `LogMessage` as it is
+/// appended so that a `Workflow` can be reconstructed by its `State::id` after a crash. Each
+/// message is stored once, by the execution that recorded it, rather than copied into every
+/// execution that replays it.
+pub trait JournalStore: Debug + Send + Sync {
+    /// Persists the top-level record of a `State`, updating its output and error if it already
+    /// exists.
+    fn save_state(&self, state: &State) -> Result<()>;
+
+    /// Persists an `Execution` at the given sequence number.
+    fn save_execution(&self, state_id: &Uuid, sequence: usize, execution: &Execution)
+        -> Result<()>;
+
+    /// Persists a single `LogMessage` recorded by an execution at the given position of the
+    /// log, replacing and dropping any message previously stored at that position, such as
+    /// a failed one.
+    ///
+    /// A message delivering a `Signal` carries the id of the signal, which must be marked as
+    /// delivered at the position in the same write, so a signal is neither lost nor delivered
+    /// twice. A detached message that is journaled again is no longer detached.
+    fn save_message(
+        &self,
+        execution_id: &Uuid,
+        position: usize,
+        log_message: &LogMessage,
+    ) -> Result<()>;
+
+    /// Drops the messages at and after the given position of the log of a `State`.
+    ///
+    /// The signals delivered by the dropped messages are pending again, and the messages that
+    /// started child workflows are kept as detached, so a retry reattaches the children instead
+    /// of starting them again.
+    fn truncate_log(&self, state_id: &Uuid, position: usize) -> Result<()>;
+
+    /// Persists the `Outcome` of a finished execution.
+    fn save_outcome(&self, execution_id: &Uuid, outcome: &Outcome) -> Result<()>;
+
+    /// Drops the executions and messages of a `State` that continued as new, and persists the
+    /// input and seed of its new run.
+    fn continue_as_new(&self, state: &State) -> Result<()>;
+
+    /// Persists a `Signal` sent to a workflow.
+    fn save_signal(&self, state_id: &Uuid, signal: &Signal) -> Result<()>;
+
+    /// Loads a `State` with all of its executions, undelivered signals and detached messages,
+    /// or `None` if the id is unknown.
+    fn load_state(&self, id: &Uuid) -> Result<Option<State>>;
+}
+
+/// A `JournalStore` backed by a SQLite database.
+#[derive(Debug)]
+pub struct SqliteJournalStore {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteJournalStore {
+    /// Opens (or creates) a SQLite journal at the given path.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    /// Opens a transient SQLite journal held in memory.
+    pub fn open_in_memory() -> Result<Self> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    fn from_connection(mut connection: Connection) -> Result<Self> {
+        connection.execute_batch(
+            "
+            PRAGMA journal_mode = WAL;
+            PRAGMA foreign_keys = ON;
+            ",
+        )?;
+        migrate(&mut connection)?;
+
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+}
+
+/// Version of the schema created by `migrate`, stored as the `user_version` of the database.
+const SCHEMA_VERSION: i64 = 3;
+
+/// Columns added to the tables of the first journals, which were created without them.
+///
+/// Messages journaled before requests were stored have no request to check a replay against,
+/// so workflows that were in flight when such a journal was written cannot be resumed.
+const ADDED_COLUMNS: &[(&str, &str, &str)] = &[
+    ("state", "component", "TEXT"),
+    ("state", "input", "TEXT NOT NULL DEFAULT 'null'"),
+    ("state", "output", "TEXT"),
+    ("state", "seed", "INTEGER"),
+    ("execution", "outcome", "TEXT"),
+    ("log_message", "request", "TEXT NOT NULL DEFAULT 'null'"),
+];
+
+/// Creates the schema of the journal or brings an older one up to date.
+fn migrate(connection: &mut Connection) -> Result<()> {
+    let transaction = connection.transaction()?;
+    let version = transaction.query_row("PRAGMA user_version", [], |row| row.get::<_, i64>(0))?;
+    if version > SCHEMA_VERSION {
+        return Err(anyhow!(
+            "journal schema version {version} is newer than the supported version {SCHEMA_VERSION}"
+        ));
+    }
+
+    if version < 1 {
+        // Journals written before the schema was versioned may lack any of the later columns.
+        transaction.execute_batch(
+            "
+            CREATE TABLE IF NOT EXISTS state (
+                id TEXT PRIMARY KEY,
+                created INTEGER NOT NULL,
+                component TEXT,
+                input TEXT NOT NULL,
+                output TEXT,
+                seed INTEGER
+            );
+
+            CREATE TABLE IF NOT EXISTS execution (
+                id TEXT PRIMARY KEY,
+                state_id TEXT NOT NULL REFERENCES state(id),
+                sequence INTEGER NOT NULL,
+                created INTEGER NOT NULL,
+                outcome TEXT
+            );
+
+            CREATE TABLE IF NOT EXISTS log_message (
+                execution_id TEXT NOT NULL REFERENCES execution(id),
+                position INTEGER NOT NULL,
+                created INTEGER NOT NULL,
+                success INTEGER NOT NULL,
+                request TEXT NOT NULL,
+                message TEXT NOT NULL,
+                PRIMARY KEY (execution_id, position)
+            );
+
+            CREATE TABLE IF NOT EXISTS signal (
+                id TEXT PRIMARY KEY,
+                state_id TEXT NOT NULL,
+                created INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                payload BLOB NOT NULL,
+                consumed INTEGER NOT NULL DEFAULT 0
+            );
+            ",
+        )?;
+        for (table, column, definition) in ADDED_COLUMNS {
+            let exists = transaction.query_row(
+                "SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2",
+                params![table, column],
+                |row| row.get::<_, bool>(0),
+            )?;
+            if !exists {
+                transaction.execute(
+                    &format!("ALTER TABLE {table} ADD COLUMN {column} {definition}"),
+                    [],
+                )?;
+            }
+        }
+    }
+
+    if version < 2 {
+        // Delivered signals remember their position, and dropped child messages are kept.
+        transaction.execute_batch(
+            "
+            ALTER TABLE signal ADD COLUMN position INTEGER;
+
+            CREATE TABLE detached_message (
+                message_id TEXT PRIMARY KEY,
+                state_id TEXT NOT NULL REFERENCES state(id),
+                created INTEGER NOT NULL,
+                request TEXT NOT NULL,
+                message TEXT NOT NULL
+            );
+            ",
+        )?;
+    }
+
+    if version < 3 {
+        // Workflows that failed for good keep their error, so they are not run again.
+        transaction.execute_batch("ALTER TABLE state ADD COLUMN error TEXT;")?;
+    }
+
+    transaction.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+    transaction.commit()?;
+    Ok(())
+}
+
+impl JournalStore for SqliteJournalStore {
+    fn save_state(&self, state: &State) -> Result<()> {
+        let connection = self.connection.lock().unwrap();
+        connection.execute(
+            "INSERT INTO state (id, created, component, input, output, seed, error) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7) ON CONFLICT (id) DO UPDATE SET output = excluded.output, error = excluded.error",
+            params![
+                state.id.to_string(),
+                to_nanos(state.created)?,
+                state.component,
+                serde_json::to_string(&state.input)?,
+                state.output.as_ref().map(serde_json::to_string).transpose()?,
+                // SQLite integers are signed, so the seed is stored with its bits reinterpreted.
+                state.seed as i64,
+                state.error
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn save_execution(
+        &self,
+        state_id: &Uuid,
+        sequence: usize,
+        execution: &Execution,
+    ) -> Result<()> {
+        let connection = self.connection.lock().unwrap();
+        connection.execute(
+            "INSERT OR REPLACE INTO execution (id, state_id, sequence, created, outcome) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                execution.id.to_string(),
+                state_id.to_string(),
+                sequence,
+                to_nanos(execution.created)?,
+                execution.outcome.as_ref().map(serde_json::to_string).transpose()?
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn save_message(
+        &self,
+        execution_id: &Uuid,
+        position: usize,
+        log_message: &LogMessage,
+    ) -> Result<()> {
+        let mut connection = self.connection.lock().unwrap();
+        let transaction = connection.transaction()?;
+        // The message supersedes the one other executions of the state stored at its position.
+        transaction.execute(
+            "DELETE FROM log_message WHERE position = ?2 AND execution_id != ?1 AND execution_id IN (SELECT id FROM execution WHERE state_id = (SELECT state_id FROM execution WHERE id = ?1))",
+            params![execution_id.to_string(), position],
+        )?;
+        transaction.execute(
+            "INSERT OR REPLACE INTO log_message (execution_id, position, created, success, request, message) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                execution_id.to_string(),
+                position,
+                to_nanos(log_message.created)?,
+                log_message.success,
+                serde_json::to_string(&log_message.request)?,
+                serde_json::to_string(&log_message.message)?
+            ],
+        )?;
+        if let Content::Signal(_) = log_message.message.content {
+            transaction.execute(
+                "UPDATE signal SET consumed = 1, position = ?2 WHERE id = ?1",
+                params![log_message.message.id, position],
+            )?;
+        }
+        transaction.execute(
+            "DELETE FROM detached_message WHERE message_id = ?1",
+            params![log_message.message.id],
+        )?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    fn truncate_log(&self, state_id: &Uuid, position: usize) -> Result<()> {
+        let mut connection = self.connection.lock().unwrap();
+        let transaction = connection.transaction()?;
+        let id = state_id.to_string();
+        transaction.execute(
+            "UPDATE signal SET consumed = 0, position = NULL WHERE state_id = ?1 AND position >= ?2",
+            params![id, position],
+        )?;
+        let dropped = transaction
+            .prepare(
+                "SELECT created, request, message FROM log_message WHERE position >= ?2 AND execution_id IN (SELECT id FROM execution WHERE state_id = ?1)",
+            )?
+            .query_map(params![id, position], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        for (created, request, message) in dropped {
+            let message = serde_json::from_str::<HostToGuest>(&message)?;
+            if let Content::Child(_) = message.content {
+                transaction.execute(
+                    "INSERT OR REPLACE INTO detached_message (message_id, state_id, created, request, message) VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![message.id, id, created, request, serde_json::to_string(&message)?],
+                )?;
+            }
+        }
+        transaction.execute(
+            "DELETE FROM log_message WHERE position >= ?2 AND execution_id IN (SELECT id FROM execution WHERE state_id = ?1)",
+            params![id, position],
+        )?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    fn save_outcome(&self, execution_id: &Uuid, outcome: &Outcome) -> Result<()> {
+        let connection = self.connection.lock().unwrap();
+        connection.execute(
+            "UPDATE execution SET outcome = ?2 WHERE id = ?1",
+            params![execution_id.to_string(), serde_json::to_string(outcome)?],
+        )?;
+        Ok(())
+    }
+
+    fn continue_as_new(&self, state: &State) -> Result<()> {
+        let mut connection = self.connection.lock().unwrap();
+        let transaction = connection.transaction()?;
+        let id = state.id.to_string();
+        transaction.execute(
+            "DELETE FROM log_message WHERE execution_id IN (SELECT id FROM execution WHERE state_id = ?1)",
+            params![id],
+        )?;
+        transaction.execute("DELETE FROM execution WHERE state_id = ?1", params![id])?;
+        transaction.execute(
+            "DELETE FROM detached_message WHERE state_id = ?1",
+            params![id],
+        )?;
+        transaction.execute(
+            "UPDATE signal SET position = NULL WHERE state_id = ?1",
+            params![id],
+        )?;
+        transaction.execute(
+            "UPDATE state SET input = ?2, output = NULL, seed = ?3, error = NULL WHERE id = ?1",
+            params![id, serde_json::to_string(&state.input)?, state.seed as i64],
+        )?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    fn save_signal(&self, state_id: &Uuid, signal: &Signal) -> Result<()> {
+        let connection = self.connection.lock().unwrap();
+        connection.execute(
+            "INSERT INTO signal (id, state_id, created, name, payload) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                signal.id.to_string(),
+                state_id.to_string(),
+                to_nanos(signal.created)?,
+                signal.name,
+                signal.payload
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn load_state(&self, id: &Uuid) -> Result<Option<State>> {
+        let connection = self.connection.lock().unwrap();
+
+        let row = connection
+            .query_row(
+                "SELECT created, component, input, output, seed, error FROM state WHERE id = ?1",
+                params![id.to_string()],
+                |row| {
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, Option<String>>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, Option<String>>(3)?,
+                        row.get::<_, Option<i64>>(4)?,
+                        row.get::<_, Option<String>>(5)?,
+                    ))
+                },
+            )
+            .optional()?;
+        let Some((created, component, input, output, seed, error)) = row else {
+            return Ok(None);
+        };
+
+        let executions = connection
+            .prepare(
+                "SELECT id, created, outcome FROM execution WHERE state_id = ?1 ORDER BY sequence",
+            )?
+            .query_map(params![id.to_string()], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                ))
+            })?
+            .map(|row| {
+                let (execution_id, created, outcome) = row?;
+                Ok(Execution::restore(
+                    Uuid::parse_str(&execution_id)?,
+                    from_nanos(created),
+                    outcome.as_deref().map(serde_json::from_str).transpose()?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // The log is rebuilt by position, where the message of the latest execution wins, as
+        // journals written before messages were stored once hold a copy in every execution.
+        let mut log = vec![];
+        let messages = connection
+            .prepare(
+                "SELECT m.position, m.created, m.success, m.request, m.message FROM log_message m JOIN execution e ON e.id = m.execution_id WHERE e.state_id = ?1 ORDER BY m.position, e.sequence",
+            )?
+            .query_map(params![id.to_string()], |row| {
+                Ok((
+                    row.get::<_, usize>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, bool>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        for (position, created, success, request, message) in messages {
+            let log_message = LogMessage::restore(
+                from_nanos(created),
+                success,
+                serde_json::from_str(&request)?,
+                serde_json::from_str(&message)?,
+            );
+            match position.cmp(&log.len()) {
+                Ordering::Less => log[position] = log_message,
+                Ordering::Equal => log.push(log_message),
+                Ordering::Greater => {
+                    return Err(anyhow!("journal of workflow {id} has a gap at {position}"))
+                }
+            }
+        }
+
+        let signals = connection
+            .prepare(
+                "SELECT id, created, name, payload FROM signal WHERE state_id = ?1 AND consumed = 0 ORDER BY created",
+            )?
+            .query_map(params![id.to_string()], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Vec<u8>>(3)?,
+                ))
+            })?
+            .map(|row| {
+                let (signal_id, created, name, payload) = row?;
+                Ok(Signal {
+                    id: Uuid::parse_str(&signal_id)?,
+                    created: from_nanos(created),
+                    name,
+                    payload,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let detached = connection
+            .prepare(
+                "SELECT created, request, message FROM detached_message WHERE state_id = ?1 ORDER BY created",
+            )?
+            .query_map(params![id.to_string()], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?
+            .map(|row| {
+                let (created, request, message) = row?;
+                Ok(LogMessage::restore(
+                    from_nanos(created),
+                    true,
+                    serde_json::from_str(&request)?,
+                    serde_json::from_str(&message)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut state = match seed {
+            Some(seed) => State::default().with_seed(seed as u64),
+            None => State::default(),
+        };
+        for signal in signals {
+            state = state.with_signal(signal);
+        }
+        let state = state
+            .with_id(*id)
+            .with_created(from_nanos(created))
+            .with_component(component)
+            .with_input(serde_json::from_str(&input)?)
+            .with_output(output.as_deref().map(serde_json::from_str).transpose()?)
+            .with_error(error)
+            .with_executions(executions)
+            .with_detached(detached)
+            .with_log(log)?;
+        Ok(Some(state))
+    }
+}
+
+/// Converts a `SystemTime` to nanoseconds since the unix epoch.
+fn to_nanos(time: SystemTime) -> Result<i64> {
+    Ok(i64::try_from(time.duration_since(UNIX_EPOCH)?.as_nanos())?)
+}
+
+/// Converts nanoseconds since the unix epoch to a `SystemTime`.
+fn from_nanos(nanos: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_nanos(nanos as u64)
+}
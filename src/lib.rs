@@ -0,0 +1,57 @@
+//! Host runtime for durable workflows compiled to WebAssembly components.
+//!
+//! A workflow is created from a component binary with `Executor::workflow` and driven with
+//! `Workflow::run_to_completion`. Every host call the guest makes is journaled on its `State`,
+//! so an interrupted workflow can be resumed by replaying the journal.
+//!
+//! ```no_run
+//! # async fn run(binary: Vec<u8>) -> anyhow::Result<()> {
+//! let engine = executor::engine::new()?;
+//! let executor = executor::Executor::new(&engine);
+//!
+//! let mut workflow = executor.workflow(&binary);
+//! workflow.with_input(&serde_json::json!({ "order": 42 }))?;
+//! let output = workflow.run_to_completion().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod capability;
+pub mod child;
+pub mod clock;
+mod continuation;
+pub mod engine;
+pub mod error;
+pub mod executor;
+pub mod guest;
+pub mod http;
+mod input;
+pub mod journal;
+pub mod limits;
+pub mod logger;
+pub mod policy;
+mod rand;
+pub mod registry;
+pub mod retry;
+pub mod signal;
+mod time;
+mod timer;
+pub mod workflow;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::{
+    capability::{Capability, Journaling},
+    clock::{Clock, FixedClock, ManualClock, SystemClock},
+    executor::Executor,
+    http::{Http, HttpConfig},
+    journal::{JournalStore, SqliteJournalStore},
+    limits::Limits,
+    logger::Logger,
+    policy::NetworkPolicy,
+    registry::ComponentRegistry,
+    retry::RetryPolicy,
+    workflow::{State, Workflow},
+};
+pub use wasmtime::Engine;
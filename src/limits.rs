@@ -0,0 +1,89 @@
+use crate::{engine::EPOCH_TICK, error::ResourceExhausted};
+use anyhow::Result;
+use std::time::Duration;
+use wasmtime::ResourceLimiter;
+
+/// Epoch ticks before an execution without a timeout is interrupted, which is never in
+/// practice. wasmtime adds the deadline to the current epoch of the engine, so it leaves room
+/// for the epochs that have already passed.
+pub(crate) const NO_DEADLINE: u64 = u64::MAX / 2;
+
+/// Resources a single execution of a workflow is allowed to consume.
+///
+/// Exceeding any limit traps the guest with a `ResourceExhausted` error.
+#[derive(Clone, Debug, Default)]
+pub struct Limits {
+    /// Fuel available to each execution, or `None` for no limit.
+    pub fuel: Option<u64>,
+    /// Maximum size in bytes of any linear memory.
+    pub max_memory: Option<usize>,
+    /// Maximum number of elements in any table.
+    pub max_table_elements: Option<u32>,
+    /// Wall-clock time each execution may run for.
+    ///
+    /// Requires an `Engine` created with `engine::new`, which enables epoch interruption.
+    pub timeout: Option<Duration>,
+}
+
+impl Limits {
+    pub fn with_fuel(mut self, fuel: u64) -> Self {
+        self.fuel = Some(fuel);
+        self
+    }
+
+    pub fn with_max_memory(mut self, max_memory: usize) -> Self {
+        self.max_memory = Some(max_memory);
+        self
+    }
+
+    pub fn with_max_table_elements(mut self, max_table_elements: u32) -> Self {
+        self.max_table_elements = Some(max_table_elements);
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the number of epoch ticks before the execution is interrupted.
+    pub fn epoch_deadline(&self) -> u64 {
+        match self.timeout {
+            Some(timeout) => timeout
+                .as_nanos()
+                .div_ceil(EPOCH_TICK.as_nanos())
+                .clamp(1, NO_DEADLINE as u128) as u64,
+            None => NO_DEADLINE,
+        }
+    }
+}
+
+impl ResourceLimiter for Limits {
+    fn memory_growing(
+        &mut self,
+        _current: usize,
+        desired: usize,
+        _maximum: Option<usize>,
+    ) -> Result<bool> {
+        match self.max_memory {
+            Some(max_memory) if desired > max_memory => {
+                Err(ResourceExhausted::Memory(max_memory).into())
+            }
+            _ => Ok(true),
+        }
+    }
+
+    fn table_growing(
+        &mut self,
+        _current: u32,
+        desired: u32,
+        _maximum: Option<u32>,
+    ) -> Result<bool> {
+        match self.max_table_elements {
+            Some(max_table_elements) if desired > max_table_elements => {
+                Err(ResourceExhausted::Table(max_table_elements).into())
+            }
+            _ => Ok(true),
+        }
+    }
+}
@@ -1,29 +1,120 @@
 use crate::{
+    capability::{Capability, CapabilityFuture, Journaling},
+    error::CapabilityError,
     guest::{
-        component::workflow::abi::{Content, Level},
+        component::workflow::abi::{Content, Field, Level, LogRecord},
         GuestToHost, HostToGuest,
     },
     workflow::State,
 };
-use anyhow::Result;
+use std::fmt;
 use uuid::Uuid;
 
-pub async fn call(state: &mut State, request: GuestToHost) -> Result<HostToGuest> {
-    match request {
-        GuestToHost::Log(level) => {
-            match level {
-                Level::Trace(msg) => println!("{} Trace: {}", state.id, msg),
-                Level::Debug(msg) => println!("{} Debug: {}", state.id, msg),
-                Level::Info(msg) => println!("{} Info: {}", state.id, msg),
-                Level::Warn(msg) => println!("{} Warn: {}", state.id, msg),
-                Level::Error(msg) => println!("{} Error: {}", state.id, msg),
-            };
-
-            Ok(HostToGuest {
-                id: Uuid::new_v4().into(),
-                content: Content::Unit,
-            })
+/// Emits the log records of the guest as `tracing` events with the `workflow` target.
+///
+/// Every event carries the workflow id, execution id, journal position and attempt number as
+/// structured fields, along with the target and key/values the guest logged the record with.
+///
+/// `tracing` only supports field names known at compile time, so the key/values of the guest
+/// are not separate fields: they are recorded together in the `fields` field as `key=value`
+/// pairs separated by spaces.
+///
+/// Records are journaled by position, so each record is emitted the first time the guest logs
+/// it and skipped when the workflow is replayed, unless replayed records are enabled with
+/// `with_replayed`.
+#[derive(Clone, Debug, Default)]
+pub struct Logger {
+    replayed: bool,
+}
+
+impl Logger {
+    /// Also emits records that are replayed from the journal, with the `replay` field set.
+    pub fn with_replayed(mut self, replayed: bool) -> Self {
+        self.replayed = replayed;
+        self
+    }
+}
+
+/// Emits a guest record as a `tracing` event at the given level.
+macro_rules! emit {
+    ($level:expr, $state:expr, $position:expr, $record:expr, $replay:expr) => {{
+        let state: &State = $state;
+        let record: &LogRecord = $record;
+        let execution = state.executions.last();
+        tracing::event!(
+            target: "workflow",
+            $level,
+            workflow.id = %state.id,
+            execution.id = execution.map(|execution| tracing::field::display(execution.id)),
+            position = $position,
+            attempt = state.executions.len(),
+            replay = $replay,
+            log.target = record.target.as_deref(),
+            fields = %Fields(&record.fields),
+            "{}",
+            record.message
+        )
+    }};
+}
+
+fn emit(state: &State, position: Option<usize>, level: &Level, replay: bool) {
+    match level {
+        Level::Trace(record) => emit!(tracing::Level::TRACE, state, position, record, replay),
+        Level::Debug(record) => emit!(tracing::Level::DEBUG, state, position, record, replay),
+        Level::Info(record) => emit!(tracing::Level::INFO, state, position, record, replay),
+        Level::Warn(record) => emit!(tracing::Level::WARN, state, position, record, replay),
+        Level::Error(record) => emit!(tracing::Level::ERROR, state, position, record, replay),
+    }
+}
+
+/// Formats key/values as `key=value` pairs separated by spaces.
+struct Fields<'a>(&'a [Field]);
+
+impl fmt::Display for Fields<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, field) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}={}", field.key, field.value)?;
         }
-        _ => unreachable!(),
+        Ok(())
+    }
+}
+
+impl Capability for Logger {
+    fn name(&self) -> &str {
+        "log"
+    }
+
+    // the logger journals itself so it can tell replayed records apart
+    fn journaling(&self) -> Journaling {
+        Journaling::Unjournaled
+    }
+
+    fn call<'a>(&'a self, state: &'a mut State, request: &'a GuestToHost) -> CapabilityFuture<'a> {
+        Box::pin(async move {
+            match request {
+                GuestToHost::Log(level) => {
+                    let position = state.executions.last().map(|execution| execution.position);
+                    if let Some(message) = state.replay(request)? {
+                        if self.replayed {
+                            emit(state, position, level, true);
+                        }
+                        return Ok(message);
+                    }
+
+                    emit(state, position, level, false);
+                    state.record(
+                        request,
+                        HostToGuest {
+                            id: Uuid::new_v4().into(),
+                            content: Content::Unit,
+                        },
+                    )
+                }
+                _ => Err(CapabilityError::new(self.name(), request, "unsupported request").into()),
+            }
+        })
     }
 }
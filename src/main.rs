@@ -1,32 +1,91 @@
-mod guest;
-mod http;
-mod logger;
-mod rand;
-mod time;
-mod workflow;
-
-#[cfg(test)]
-mod test;
-
 use anyhow::Result;
-use wasmtime::{self, Config, Engine};
+use executor::{engine, ComponentRegistry, Executor, HttpConfig, Logger, SqliteJournalStore};
+use std::sync::Arc;
+use uuid::Uuid;
+
+const USAGE: &str =
+    "USAGE: demo WASM [--input JSON] [--journal PATH] [--resume ID] [--cache-dir PATH] [--component NAME=WASM]... [--seed N] [--replayed-logs] [--proxy URL] [--ca-cert PEM]...";
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let path = std::env::args().nth(1).expect("USAGE: demo WASM");
+    // Guest log records are emitted as `tracing` events, filtered with `RUST_LOG`
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .init();
+
+    let mut args = std::env::args().skip(1);
+    let path = args.next().expect(USAGE);
     let binary = std::fs::read(path)?;
 
-    // Enable component model (which isn't supported by default)
-    let mut config = Config::new();
-    config.wasm_component_model(true);
-    config.async_support(true);
-    config.consume_fuel(true);
+    let mut input = serde_json::Value::Null;
+    let mut journal = None;
+    let mut resume = None;
+    let mut seed = None;
+    let mut cache_dir = None;
+    let mut components = vec![];
+    let mut replayed_logs = false;
+    let mut http = HttpConfig::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = serde_json::from_str(&args.next().expect(USAGE))?,
+            "--journal" => journal = Some(args.next().expect(USAGE)),
+            "--resume" => resume = Some(Uuid::parse_str(&args.next().expect(USAGE))?),
+            "--seed" => seed = Some(args.next().expect(USAGE).parse()?),
+            "--cache-dir" => cache_dir = Some(args.next().expect(USAGE)),
+            "--component" => {
+                let component = args.next().expect(USAGE);
+                let (name, path) = component.split_once('=').expect(USAGE);
+                components.push((name.to_owned(), std::fs::read(path)?));
+            }
+            "--replayed-logs" => replayed_logs = true,
+            "--proxy" => http = http.with_proxy(args.next().expect(USAGE)),
+            "--ca-cert" => {
+                http = http.with_root_certificate(std::fs::read(args.next().expect(USAGE))?)
+            }
+            _ => panic!("{USAGE}"),
+        }
+    }
+
+    let engine = engine::new()?;
 
-    // Create a wasmtime execution context
-    let engine = Engine::new(&config)?;
+    // `--proxy` and `--ca-cert` configure the client shared by all HTTP requests
+    let mut executor = Executor::new(&engine)
+        .with_http(&http)?
+        .with_capability(Logger::default().with_replayed(replayed_logs));
+    if let Some(path) = cache_dir {
+        executor = executor.with_registry(ComponentRegistry::new(&engine).with_cache_dir(path));
+    }
+    if let Some(path) = journal {
+        executor = executor.with_journal(Arc::new(SqliteJournalStore::open(path)?));
+    }
+    for (name, binary) in components {
+        executor.register(name, binary);
+    }
 
-    let mut workflow = workflow::Workflow::new(&engine, &binary);
-    workflow.execute().await?;
+    let mut workflow = match resume {
+        Some(id) => executor.load(&binary, &id)?,
+        None => {
+            let mut workflow = executor.workflow(&binary);
+            workflow.with_input(&input)?;
+            if let Some(seed) = seed {
+                workflow.with_seed(seed);
+            }
+            workflow
+        }
+    };
+    // The seed is logged so a failed run can be reproduced with `--seed`
+    tracing::info!(
+        workflow.id = %workflow.state().id,
+        seed = workflow.state().seed(),
+        "starting workflow"
+    );
+    let output = workflow.run_to_completion().await?;
+    if !output.is_null() {
+        println!("{output}");
+    }
 
     Ok(())
 }
@@ -0,0 +1,174 @@
+use ipnet::IpNet;
+use reqwest::{Method, Url};
+use std::net::IpAddr;
+
+/// Restricts which endpoints the guest may reach through the HTTP capability.
+///
+/// Every list that is empty leaves its dimension unrestricted, so the default policy allows
+/// everything. Denied networks take precedence over allowed ones. Requests that violate the
+/// policy, including redirects to a forbidden endpoint, fail with `Kind::Denied`.
+#[derive(Clone, Debug, Default)]
+pub struct NetworkPolicy {
+    /// Hosts that may be called, where `*.example.com` matches any subdomain of `example.com`.
+    pub allowed_hosts: Vec<String>,
+    /// URL schemes that may be used, e.g. `https`.
+    pub allowed_schemes: Vec<String>,
+    /// Ports that may be connected to.
+    pub allowed_ports: Vec<u16>,
+    /// Methods that may be used.
+    pub allowed_methods: Vec<Method>,
+    /// Networks the resolved address of the host must be part of.
+    pub allowed_networks: Vec<IpNet>,
+    /// Networks the resolved address of the host must not be part of.
+    pub denied_networks: Vec<IpNet>,
+}
+
+impl NetworkPolicy {
+    /// Creates a policy that only allows HTTPS requests to public addresses.
+    ///
+    /// Loopback, private, shared, link-local (including cloud metadata endpoints), IETF protocol
+    /// assignment, benchmarking and unique local networks are denied, as are IPv4-mapped and
+    /// NAT64 addresses, which could reach any of them over IPv6.
+    pub fn public() -> Self {
+        let private = [
+            "0.0.0.0/8",
+            "10.0.0.0/8",
+            "100.64.0.0/10",
+            "127.0.0.0/8",
+            "169.254.0.0/16",
+            "172.16.0.0/12",
+            "192.0.0.0/24",
+            "192.168.0.0/16",
+            "198.18.0.0/15",
+            "::1/128",
+            "::ffff:0:0/96",
+            "64:ff9b::/96",
+            "fc00::/7",
+            "fe80::/10",
+        ];
+        Self {
+            allowed_schemes: vec!["https".to_owned()],
+            denied_networks: private
+                .iter()
+                .map(|network| network.parse().expect("network should be valid"))
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_allowed_host(mut self, host: impl Into<String>) -> Self {
+        self.allowed_hosts.push(host.into());
+        self
+    }
+
+    pub fn with_allowed_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.allowed_schemes.push(scheme.into());
+        self
+    }
+
+    pub fn with_allowed_port(mut self, port: u16) -> Self {
+        self.allowed_ports.push(port);
+        self
+    }
+
+    pub fn with_allowed_method(mut self, method: Method) -> Self {
+        self.allowed_methods.push(method);
+        self
+    }
+
+    pub fn with_allowed_network(mut self, network: IpNet) -> Self {
+        self.allowed_networks.push(network);
+        self
+    }
+
+    pub fn with_denied_network(mut self, network: IpNet) -> Self {
+        self.denied_networks.push(network);
+        self
+    }
+
+    /// Checks a request before it is sent, returning the reason it is denied.
+    ///
+    /// The host is resolved if the policy restricts networks, so a host name cannot be used to
+    /// reach a denied address.
+    pub async fn check(&self, method: &Method, url: &Url) -> Result<(), String> {
+        if !self.allowed_methods.is_empty() && !self.allowed_methods.contains(method) {
+            return Err(format!("method {method} is not allowed"));
+        }
+
+        let scheme = url.scheme();
+        if !self.allowed_schemes.is_empty()
+            && !self
+                .allowed_schemes
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(scheme))
+        {
+            return Err(format!("scheme {scheme} is not allowed"));
+        }
+
+        let host = url.host_str().ok_or_else(|| format!("{url} has no host"))?;
+        if !self.allowed_hosts.is_empty()
+            && !self
+                .allowed_hosts
+                .iter()
+                .any(|allowed| host_matches(allowed, host))
+        {
+            return Err(format!("host {host} is not allowed"));
+        }
+
+        let port = url
+            .port_or_known_default()
+            .ok_or_else(|| format!("{url} has no port"))?;
+        if !self.allowed_ports.is_empty() && !self.allowed_ports.contains(&port) {
+            return Err(format!("port {port} is not allowed"));
+        }
+
+        if self.allowed_networks.is_empty() && self.denied_networks.is_empty() {
+            return Ok(());
+        }
+        let literal = host.trim_start_matches('[').trim_end_matches(']');
+        let addresses = match literal.parse::<IpAddr>() {
+            Ok(address) => vec![address],
+            Err(_) => tokio::net::lookup_host((host, port))
+                .await
+                .map_err(|err| format!("host {host} could not be resolved: {err}"))?
+                .map(|address| address.ip())
+                .collect(),
+        };
+        addresses
+            .into_iter()
+            .try_for_each(|address| self.check_address(address))
+    }
+
+    /// Checks an address the host resolved to, returning the reason it is denied.
+    pub fn check_address(&self, address: IpAddr) -> Result<(), String> {
+        if self
+            .denied_networks
+            .iter()
+            .any(|network| network.contains(&address))
+        {
+            return Err(format!("address {address} is in a denied network"));
+        }
+        if !self.allowed_networks.is_empty()
+            && !self
+                .allowed_networks
+                .iter()
+                .any(|network| network.contains(&address))
+        {
+            return Err(format!("address {address} is not in an allowed network"));
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether `host` matches an allowed host, which may start with a `*.` wildcard.
+fn host_matches(allowed: &str, host: &str) -> bool {
+    match allowed.strip_prefix("*.") {
+        Some(domain) => host
+            .len()
+            .checked_sub(domain.len() + 1)
+            .is_some_and(|split| {
+                host.as_bytes()[split] == b'.' && host[split + 1..].eq_ignore_ascii_case(domain)
+            }),
+        None => allowed.eq_ignore_ascii_case(host),
+    }
+}
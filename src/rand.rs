@@ -1,47 +1,76 @@
 use crate::{
+    capability::{Capability, CapabilityFuture},
+    error::CapabilityError,
     guest::{
-        component::workflow::abi::{Content, Types},
+        component::workflow::abi::{Content, RandomType, Types},
         GuestToHost, HostToGuest,
     },
     workflow::State,
 };
-use anyhow::Result;
-use rand::Rng;
+use rand::{
+    distributions::{Alphanumeric, DistString},
+    rngs::StdRng,
+    Rng,
+};
 use uuid::Uuid;
 
-pub async fn call(state: &mut State, request: GuestToHost) -> Result<HostToGuest> {
-    let rng = state.rng.clone();
-    match request {
-        GuestToHost::Random(ty) => match ty {
-            Types::BoolType(_) => Ok(state
-                .retrieve_or_else(|| async {
-                    let mut rng = rng.lock().unwrap();
-                    Ok(HostToGuest {
-                        id: Uuid::new_v4().into(),
-                        content: Content::Value(Types::BoolType(rng.gen::<bool>())),
-                    })
-                })
-                .await?),
-            Types::I32Type(_) => Ok(state
-                .retrieve_or_else(|| async {
-                    let mut rng = rng.lock().unwrap();
-                    Ok(HostToGuest {
-                        id: Uuid::new_v4().into(),
-                        content: Content::Value(Types::I32Type(rng.gen::<i32>() as u32)),
-                    })
-                })
-                .await?),
-            Types::F32Type(_) => Ok(state
-                .retrieve_or_else(|| async {
-                    let mut rng = rng.lock().unwrap();
-                    Ok(HostToGuest {
-                        id: Uuid::new_v4().into(),
-                        content: Content::Value(Types::F32Type(rng.gen::<f32>())),
-                    })
-                })
-                .await?),
-            Types::StringType(_) => unreachable!(),
-        },
-        _ => unreachable!(),
+/// Maximum number of random bytes or characters the guest may draw with one request.
+const MAX_LEN: usize = 1 << 20;
+
+/// Draws random values from the random number generator of the workflow.
+///
+/// String and byte requests carry the number of characters or bytes the guest wants to draw.
+pub struct Random;
+
+impl Capability for Random {
+    fn name(&self) -> &str {
+        "random"
     }
+
+    fn call<'a>(&'a self, state: &'a mut State, request: &'a GuestToHost) -> CapabilityFuture<'a> {
+        Box::pin(async move {
+            let value = match request {
+                GuestToHost::Random(ty) => draw(&mut state.rng.lock().unwrap(), ty)
+                    .map_err(|reason| CapabilityError::new(self.name(), request, reason))?,
+                _ => {
+                    return Err(
+                        CapabilityError::new(self.name(), request, "unsupported request").into(),
+                    )
+                }
+            };
+            Ok(HostToGuest {
+                id: Uuid::new_v4().into(),
+                content: Content::Value(value),
+            })
+        })
+    }
+}
+
+/// Draws a random value of the requested type, returning the reason the request is invalid.
+pub(crate) fn draw(rng: &mut StdRng, ty: &RandomType) -> Result<Types, String> {
+    Ok(match *ty {
+        RandomType::BoolType => Types::BoolType(rng.gen::<bool>()),
+        RandomType::I32Type => Types::I32Type(rng.gen::<i32>() as u32),
+        RandomType::F32Type => Types::F32Type(rng.gen::<f32>()),
+        RandomType::U64Type => Types::U64Type(rng.gen::<u64>()),
+        RandomType::F64Type => Types::F64Type(rng.gen::<f64>()),
+        RandomType::StringType(len) if len as usize <= MAX_LEN => {
+            Types::StringType(Alphanumeric.sample_string(rng, len as usize))
+        }
+        RandomType::BytesType(len) if len as usize <= MAX_LEN => {
+            let mut bytes = vec![0; len as usize];
+            rng.fill(&mut bytes[..]);
+            Types::BytesType(bytes)
+        }
+        RandomType::StringType(_) => {
+            return Err(format!(
+                "at most {MAX_LEN} random characters can be drawn at once"
+            ))
+        }
+        RandomType::BytesType(_) => {
+            return Err(format!(
+                "at most {MAX_LEN} random bytes can be drawn at once"
+            ))
+        }
+    })
 }
@@ -0,0 +1,94 @@
+use crate::{guest, workflow::State};
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+use wasmtime::{
+    component::{Component, Linker},
+    Engine,
+};
+
+/// Compiles workflow components once per binary and keeps them ready to instantiate.
+///
+/// Components are keyed by the SHA-256 hash of their binary, so every `Workflow` sharing a
+/// registry pays the Cranelift compile cost only once, no matter how often it is retried.
+#[derive(Clone)]
+pub struct ComponentRegistry {
+    engine: Engine,
+    linker: Arc<Linker<State>>,
+    cache_dir: Option<PathBuf>,
+    components: Arc<Mutex<HashMap<String, guest::WorkflowPre<State>>>>,
+}
+
+impl ComponentRegistry {
+    pub fn new(engine: &Engine) -> Self {
+        let mut linker = Linker::new(engine);
+        guest::Workflow::add_to_linker(&mut linker, |state: &mut State| state)
+            .expect("workflow imports should link");
+
+        Self {
+            engine: engine.to_owned(),
+            linker: Arc::new(linker),
+            cache_dir: None,
+            components: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Persists compiled artifacts to the given directory so they survive process restarts.
+    ///
+    /// Artifacts must only be loaded from a directory that is trusted, as they are native code
+    /// that is executed without further validation.
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+
+    /// Returns the `Engine` components are compiled for.
+    pub fn engine(&self) -> &Engine {
+        &self.engine
+    }
+
+    /// Returns the pre-instantiated component for the given binary, compiling it on first use.
+    pub fn instance_pre(&self, binary: &[u8]) -> Result<guest::WorkflowPre<State>> {
+        let hash = format!("{:x}", Sha256::digest(binary));
+
+        if let Some(instance_pre) = self.components.lock().unwrap().get(&hash) {
+            return Ok(instance_pre.clone());
+        }
+
+        let component = self.load_or_compile(&hash, binary)?;
+        let instance_pre = guest::WorkflowPre::new(self.linker.instantiate_pre(&component)?)?;
+        self.components
+            .lock()
+            .unwrap()
+            .insert(hash, instance_pre.clone());
+
+        Ok(instance_pre)
+    }
+
+    /// Loads a serialized artifact from the cache directory, or compiles the binary and writes
+    /// the artifact back to it.
+    fn load_or_compile(&self, hash: &str, binary: &[u8]) -> Result<Component> {
+        let Some(cache_dir) = &self.cache_dir else {
+            return Component::from_binary(&self.engine, binary);
+        };
+
+        let path = cache_dir.join(format!("{hash}.cwasm"));
+        if path.exists() {
+            // SAFETY: the cache directory only contains artifacts serialized by this registry
+            // and `deserialize_file` rejects artifacts built by an incompatible `Engine`.
+            if let Ok(component) = unsafe { Component::deserialize_file(&self.engine, &path) } {
+                return Ok(component);
+            }
+        }
+
+        let component = Component::from_binary(&self.engine, binary)?;
+        std::fs::create_dir_all(cache_dir)?;
+        std::fs::write(&path, component.serialize()?)?;
+
+        Ok(component)
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    error::{CapabilityError, Denied, NonDeterminismError, ResourceExhausted},
+    guest::WorkflowError,
+};
+use rand::Rng;
+use std::time::Duration;
+
+/// Controls how a failed workflow execution is retried by `Workflow::run_to_completion`.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Maximum number of executions before giving up, or `None` to retry indefinitely.
+    pub max_attempts: Option<usize>,
+    /// Delay before the first retry.
+    pub initial_interval: Duration,
+    /// Multiplier applied to the delay after every failed attempt.
+    pub backoff_coefficient: f64,
+    /// Upper bound for the delay between two attempts.
+    pub max_interval: Duration,
+    /// Fraction of the delay that is randomized, e.g. `0.2` yields a delay within ±20%.
+    pub jitter: f64,
+    /// Failures whose `WorkflowError::error` contains any of these strings are not retried.
+    pub non_retryable_errors: Vec<String>,
+}
+
+impl Default for RetryPolicy {
+    /// Creates a `RetryPolicy` that retries indefinitely with exponential backoff.
+    fn default() -> Self {
+        Self {
+            max_attempts: None,
+            initial_interval: Duration::from_secs(1),
+            backoff_coefficient: 2.0,
+            max_interval: Duration::from_secs(100),
+            jitter: 0.2,
+            non_retryable_errors: vec![],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a `RetryPolicy` that never retries.
+    pub fn none() -> Self {
+        Self::default().with_max_attempts(Some(1))
+    }
+
+    /// Sets the maximum number of executions, or `None` to retry indefinitely.
+    pub fn with_max_attempts(mut self, max_attempts: Option<usize>) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn with_initial_interval(mut self, initial_interval: Duration) -> Self {
+        self.initial_interval = initial_interval;
+        self
+    }
+
+    /// Sets the multiplier applied to the delay after every failed attempt.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coefficient is not finite or is less than `1.0`, as the delay would not
+    /// grow.
+    pub fn with_backoff_coefficient(mut self, backoff_coefficient: f64) -> Self {
+        assert!(
+            backoff_coefficient.is_finite() && backoff_coefficient >= 1.0,
+            "backoff coefficient {backoff_coefficient} must be finite and at least 1.0"
+        );
+        self.backoff_coefficient = backoff_coefficient;
+        self
+    }
+
+    /// Sets the upper bound for the delay between two attempts.
+    pub fn with_max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Sets the fraction of the delay that is randomized, clamped to `0.0..=1.0`.
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Stops retrying failures whose `WorkflowError::error` contains the given string.
+    pub fn with_non_retryable_error(mut self, error: impl Into<String>) -> Self {
+        self.non_retryable_errors.push(error.into());
+        self
+    }
+
+    /// Returns whether another attempt should be made after `attempt` executions failed with `err`.
+    pub fn should_retry(&self, attempt: usize, err: &anyhow::Error) -> bool {
+        if self
+            .max_attempts
+            .is_some_and(|max_attempts| attempt >= max_attempts)
+        {
+            return false;
+        }
+
+        // replaying a changed binary will fail the same way every time
+        if err.downcast_ref::<NonDeterminismError>().is_some() {
+            return false;
+        }
+
+        // the guest will make the same invalid request again
+        if err.downcast_ref::<CapabilityError>().is_some() {
+            return false;
+        }
+
+        // the network policy will deny the request again
+        if err.downcast_ref::<Denied>().is_some() {
+            return false;
+        }
+
+        // a guest that ran out of fuel, memory or table space will do so again, whereas a
+        // timeout may be caused by a slow dependency
+        if let Some(exhausted) = err.downcast_ref::<ResourceExhausted>() {
+            return matches!(exhausted, ResourceExhausted::Timeout(_));
+        }
+
+        match err.downcast_ref::<WorkflowError>() {
+            Some(err) => !self
+                .non_retryable_errors
+                .iter()
+                .any(|non_retryable| err.error.contains(non_retryable)),
+            None => true,
+        }
+    }
+
+    /// Returns the delay to wait after `attempt` executions have failed.
+    ///
+    /// A delay that cannot be represented, e.g. because `max_interval` is `Duration::MAX`,
+    /// is capped at `max_interval`.
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as usize) as i32;
+        let interval =
+            self.initial_interval.as_secs_f64() * self.backoff_coefficient.powi(exponent);
+        let interval = interval.min(self.max_interval.as_secs_f64());
+
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        let interval = if jitter > 0.0 {
+            interval * rand::thread_rng().gen_range(1.0 - jitter..=1.0 + jitter)
+        } else {
+            interval
+        };
+
+        Duration::try_from_secs_f64(interval.min(self.max_interval.as_secs_f64()))
+            .unwrap_or(self.max_interval)
+    }
+}
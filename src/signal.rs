@@ -0,0 +1,121 @@
+use crate::{
+    capability::{Capability, CapabilityFuture, Journaling},
+    error::{CapabilityError, Suspend},
+    guest::{component::workflow::abi::Content, GuestToHost, HostToGuest},
+    workflow::State,
+};
+use serde::{Deserialize, Serialize};
+use std::{sync::Mutex, time::SystemTime};
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+/// An external event delivered to a workflow.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Signal {
+    /// Unique identifier for the signal.
+    pub id: Uuid,
+    /// Time when the signal was received.
+    pub created: SystemTime,
+    /// Name the guest waits for.
+    pub name: String,
+    /// JSON encoded payload.
+    pub payload: Vec<u8>,
+}
+
+impl Signal {
+    /// Creates a new `Signal` with the given name and payload.
+    pub fn new(name: impl Into<String>, payload: Vec<u8>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created: SystemTime::now(),
+            name: name.into(),
+            payload,
+        }
+    }
+}
+
+/// The signals that have been sent to a workflow but not yet delivered to the guest.
+#[derive(Debug, Default)]
+pub struct Signals {
+    pending: Mutex<Vec<Signal>>,
+    notify: Notify,
+}
+
+impl Signals {
+    /// Queues a signal and wakes any workflow waiting for one.
+    pub fn push(&self, signal: Signal) {
+        self.pending.lock().unwrap().push(signal);
+        self.notify.notify_waiters();
+    }
+
+    /// Puts a signal that could not be delivered back in front of the pending ones.
+    pub(crate) fn requeue(&self, signal: Signal) {
+        self.pending.lock().unwrap().insert(0, signal);
+        self.notify.notify_waiters();
+    }
+
+    /// Removes and returns the oldest pending signal with the given name.
+    pub fn take(&self, name: &str) -> Option<Signal> {
+        let mut pending = self.pending.lock().unwrap();
+        let index = pending.iter().position(|signal| signal.name == name)?;
+        Some(pending.remove(index))
+    }
+
+    /// Waits until a signal with the given name is pending.
+    pub async fn wait(&self, name: &str) {
+        loop {
+            let notified = self.notify.notified();
+            if self
+                .pending
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|signal| signal.name == name)
+            {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// Delivers pending signals to a guest waiting for them.
+///
+/// The delivery is journaled by the capability itself: the message carries the id of the
+/// signal, so the journal marks the signal consumed in the same write that records the
+/// message, and a signal whose delivery could not be recorded is put back in the inbox.
+pub struct Inbox;
+
+impl Capability for Inbox {
+    fn name(&self) -> &str {
+        "signal"
+    }
+
+    fn journaling(&self) -> Journaling {
+        Journaling::Unjournaled
+    }
+
+    fn call<'a>(&'a self, state: &'a mut State, request: &'a GuestToHost) -> CapabilityFuture<'a> {
+        Box::pin(async move {
+            match request {
+                // Once delivered the signal lives in the journal so replays see the same payload.
+                GuestToHost::WaitForSignal(name) => {
+                    if let Some(message) = state.replay(request)? {
+                        return Ok(message);
+                    }
+                    let Some(signal) = state.signals.take(name) else {
+                        return Err(Suspend::Signal(name.to_owned()).into());
+                    };
+                    let message = HostToGuest {
+                        id: signal.id.into(),
+                        content: Content::Signal(signal.payload.clone()),
+                    };
+                    state
+                        .record(request, message)
+                        .inspect_err(|_| state.signals.requeue(signal))
+                }
+                _ => Err(CapabilityError::new(self.name(), request, "unsupported request").into()),
+            }
+        })
+    }
+}
@@ -1,13 +1,16 @@
-use crate::workflow::{self, State};
+use crate::{
+    engine,
+    retry::RetryPolicy,
+    workflow::{self, State},
+};
 use anyhow::Result;
 use axum::{
     http::StatusCode,
     routing::{get, post},
     Router,
 };
-use rand::{Rng, SeedableRng};
+use rand::Rng;
 use std::time::Duration;
-use wasmtime::{Config, Engine};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn sim() -> Result<()> {
@@ -56,30 +59,19 @@ async fn sim() -> Result<()> {
         .unwrap();
     });
 
-    // Enable component model (which isn't supported by default)
-    let mut config = Config::new();
-    config.wasm_component_model(true);
-    config.async_support(true);
-    config.consume_fuel(true);
-
-    // Create a wasmtime execution context
-    let engine = Engine::new(&config)?;
+    let registry = crate::registry::ComponentRegistry::new(&engine::new()?);
     let binary = std::fs::read("target/wasm32-unknown-unknown/release/workflow_example.wasm")?;
 
     for i in 0..10 {
         println!("\nstart seed {i}");
-        let mut workflow = workflow::Workflow::new(&engine, &binary);
-        workflow.with_state(State::default().with_rng(::rand::rngs::StdRng::seed_from_u64(i)));
-
-        loop {
-            match workflow.execute().await {
-                Ok(_) => break,
-                Err(_) => {
-                    tokio::time::sleep(Duration::from_millis(100)).await;
-                    continue;
-                }
-            }
-        }
+        let mut workflow = workflow::Workflow::new(&registry, &binary);
+        workflow.with_seed(i).with_retry_policy(
+            RetryPolicy::default()
+                .with_initial_interval(Duration::from_millis(100))
+                .with_backoff_coefficient(1.0)
+                .with_jitter(0.0),
+        );
+        workflow.run_to_completion().await?;
     }
 
     _ = close_tx.send(());
@@ -87,3 +79,1491 @@ async fn sim() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn journal_round_trip() -> Result<()> {
+    use crate::{
+        guest::component::workflow::abi::{Content, GuestToHost, HostToGuest},
+        journal::{JournalStore, SqliteJournalStore},
+    };
+    use std::sync::Arc;
+
+    let journal = Arc::new(SqliteJournalStore::open_in_memory()?);
+    let mut state = State::default()
+        .with_input(serde_json::json!({ "order": 42 }))
+        .with_journal(journal.clone());
+    state.start_execution()?;
+
+    for id in ["first", "second"] {
+        state
+            .retrieve_or_else(&GuestToHost::Time, || async {
+                Ok(HostToGuest {
+                    id: id.to_string(),
+                    content: Content::Unit,
+                })
+            })
+            .await?;
+    }
+    state.set_failure("second")?;
+    state.set_output(serde_json::json!("done"))?;
+
+    let loaded = journal.load_state(&state.id)?.expect("state was persisted");
+    assert_eq!(loaded.id, state.id);
+    assert_eq!(loaded.input, state.input);
+    assert_eq!(loaded.output(), Some(&serde_json::json!("done")));
+    assert_eq!(loaded.executions.len(), 1);
+
+    let log = &loaded.log;
+    assert_eq!(log.len(), 2);
+    assert_eq!(log[0].message.id, "first");
+    assert!(log[0].success);
+    assert_eq!(log[0].request, GuestToHost::Time);
+    assert_eq!(log[1].message.id, "second");
+    assert!(!log[1].success);
+
+    Ok(())
+}
+
+#[test]
+fn journals_of_older_versions_are_migrated() -> Result<()> {
+    use crate::journal::{JournalStore, SqliteJournalStore};
+
+    // the schema of the first journals, before any column was added
+    let path = std::env::temp_dir().join(format!("journal-{}.db", uuid::Uuid::new_v4()));
+    let connection = rusqlite::Connection::open(&path)?;
+    connection.execute_batch(
+        "
+        CREATE TABLE state (id TEXT PRIMARY KEY, created INTEGER NOT NULL);
+        CREATE TABLE execution (
+            id TEXT PRIMARY KEY,
+            state_id TEXT NOT NULL REFERENCES state(id),
+            sequence INTEGER NOT NULL,
+            created INTEGER NOT NULL
+        );
+        CREATE TABLE log_message (
+            execution_id TEXT NOT NULL REFERENCES execution(id),
+            position INTEGER NOT NULL,
+            created INTEGER NOT NULL,
+            success INTEGER NOT NULL,
+            message TEXT NOT NULL,
+            PRIMARY KEY (execution_id, position)
+        );
+        ",
+    )?;
+    let id = uuid::Uuid::new_v4();
+    connection.execute(
+        "INSERT INTO state (id, created) VALUES (?1, 0)",
+        [id.to_string()],
+    )?;
+    drop(connection);
+
+    let journal = SqliteJournalStore::open(&path)?;
+    let legacy = journal.load_state(&id)?.expect("state was migrated");
+    assert_eq!(legacy.input(), &serde_json::Value::Null);
+    assert_eq!(legacy.output(), None);
+
+    let mut state = State::default()
+        .with_rng(7)
+        .with_input(serde_json::json!({ "order": 42 }));
+    journal.save_state(&state)?;
+    state.start_execution()?;
+    journal.save_execution(&state.id, 0, &state.executions()[0])?;
+    let loaded = journal.load_state(&state.id)?.expect("state was persisted");
+    assert_eq!(loaded.seed(), 7);
+    assert_eq!(loaded.input(), state.input());
+    drop(journal);
+
+    // reopening a migrated journal leaves it as it is
+    let version =
+        rusqlite::Connection::open(&path)?
+            .query_row("PRAGMA user_version", [], |row| row.get::<_, i64>(0))?;
+    assert_eq!(version, 3);
+    SqliteJournalStore::open(&path)?;
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn seed_is_journaled() -> Result<()> {
+    use crate::{
+        guest::component::workflow::abi::{GuestToHost, RandomType},
+        journal::{JournalStore, SqliteJournalStore},
+    };
+    use std::sync::Arc;
+
+    let draw = GuestToHost::Random(RandomType::U64Type);
+    let journal = Arc::new(SqliteJournalStore::open_in_memory()?);
+    let mut state = State::default()
+        .with_seed(u64::MAX)
+        .with_journal(journal.clone());
+    state.start_execution()?;
+    call(&mut state, draw.clone()).await?;
+    let second = call(&mut state, draw.clone()).await?;
+
+    // a loaded state continues the random stream after the journaled draws
+    let mut loaded = journal.load_state(&state.id)?.expect("state was persisted");
+    assert_eq!(loaded.seed(), u64::MAX);
+    loaded.start_execution()?;
+    call(&mut loaded, draw.clone()).await?;
+    assert_eq!(call(&mut loaded, draw.clone()).await?, second);
+    let third = call(&mut loaded, draw.clone()).await?;
+    assert_ne!(third.content, second.content);
+
+    let mut fresh = State::default().with_seed(u64::MAX);
+    fresh.start_execution()?;
+    for _ in 0..2 {
+        call(&mut fresh, draw.clone()).await?;
+    }
+    assert_eq!(call(&mut fresh, draw.clone()).await?.content, third.content);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn continue_as_new_starts_a_fresh_run() -> Result<()> {
+    use crate::{
+        error::ContinueAsNew,
+        guest::component::workflow::abi::{Content, GuestToHost, HostToGuest},
+        journal::{JournalStore, SqliteJournalStore},
+    };
+    use std::sync::Arc;
+
+    let journal = Arc::new(SqliteJournalStore::open_in_memory()?);
+    let mut state = State::default()
+        .with_input(serde_json::json!(1))
+        .with_journal(journal.clone());
+    state.start_execution()?;
+
+    // a failed message is replaced by the retry instead of being kept alongside it
+    let respond = |id: &'static str| {
+        move || async move {
+            Ok(HostToGuest {
+                id: id.to_string(),
+                content: Content::Unit,
+            })
+        }
+    };
+    state
+        .retrieve_or_else(&GuestToHost::Time, respond("failed"))
+        .await?;
+    state.set_failure("failed")?;
+    state.start_execution()?;
+    state
+        .retrieve_or_else(&GuestToHost::Time, respond("retried"))
+        .await?;
+    let loaded = journal.load_state(&state.id)?.expect("state was persisted");
+    assert_eq!(loaded.executions.len(), 2);
+    assert_eq!(loaded.log.len(), 1);
+    assert_eq!(loaded.log[0].message.id, "retried");
+    assert!(loaded.log[0].success);
+
+    // the failed message may be followed by messages of earlier executions
+    let mut state = State::default().with_journal(journal.clone());
+    state.start_execution()?;
+    for id in ["first", "failed", "third"] {
+        state
+            .retrieve_or_else(&GuestToHost::Time, respond(id))
+            .await?;
+    }
+    state.set_failure("failed")?;
+    state.start_execution()?;
+    for id in ["replayed", "retried", "new"] {
+        state
+            .retrieve_or_else(&GuestToHost::Time, respond(id))
+            .await?;
+    }
+    state.set_failure("first")?;
+    let loaded = journal.load_state(&state.id)?.expect("state was persisted");
+    let ids = loaded
+        .log
+        .iter()
+        .map(|log_message| (log_message.message.id.as_str(), log_message.success))
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [("first", false), ("retried", true), ("new", true)]);
+
+    let err = call(&mut state, GuestToHost::ContinueAsNew(b"2".to_vec()))
+        .await
+        .unwrap_err();
+    let Some(ContinueAsNew { input }) = err.downcast_ref::<ContinueAsNew>().cloned() else {
+        panic!("expected the workflow to continue as new");
+    };
+    let seed = state.seed();
+    state.continue_as_new(input)?;
+    assert!(state.executions.is_empty());
+    assert!(state.log.is_empty());
+    assert_ne!(state.seed(), seed);
+
+    let loaded = journal.load_state(&state.id)?.expect("state was persisted");
+    assert_eq!(loaded.input, serde_json::json!(2));
+    assert_eq!(loaded.seed(), state.seed());
+    assert!(loaded.executions.is_empty());
+    assert!(loaded.log.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn replay_detects_nondeterminism() -> Result<()> {
+    use crate::{
+        error::NonDeterminismError,
+        guest::component::workflow::abi::{Content, GuestToHost, HostToGuest},
+    };
+
+    let respond = || async {
+        Ok(HostToGuest {
+            id: uuid::Uuid::new_v4().into(),
+            content: Content::Unit,
+        })
+    };
+
+    let mut state = State::default();
+    state.start_execution()?;
+    state.retrieve_or_else(&GuestToHost::Time, respond).await?;
+
+    // replaying the same call succeeds
+    state.start_execution()?;
+    state.retrieve_or_else(&GuestToHost::Time, respond).await?;
+
+    // replaying a different call at the same position fails
+    state.start_execution()?;
+    let err = state
+        .retrieve_or_else(&GuestToHost::Input, respond)
+        .await
+        .unwrap_err();
+    let err = err
+        .downcast_ref::<NonDeterminismError>()
+        .expect("a NonDeterminismError");
+    assert_eq!(err.position, 0);
+    assert_eq!(err.expected, GuestToHost::Time);
+    assert_eq!(err.actual, GuestToHost::Input);
+
+    Ok(())
+}
+
+#[test]
+fn retry_policy() {
+    use crate::{
+        error::{NonDeterminismError, ResourceExhausted},
+        guest::GuestToHost,
+        guest::WorkflowError,
+    };
+
+    let policy = RetryPolicy::default()
+        .with_max_attempts(Some(3))
+        .with_initial_interval(Duration::from_secs(1))
+        .with_max_interval(Duration::from_secs(5))
+        .with_jitter(0.0)
+        .with_non_retryable_error("Forbidden");
+
+    assert_eq!(policy.backoff(1), Duration::from_secs(1));
+    assert_eq!(policy.backoff(2), Duration::from_secs(2));
+    assert_eq!(policy.backoff(3), Duration::from_secs(4));
+    assert_eq!(policy.backoff(4), Duration::from_secs(5));
+
+    let retryable = anyhow::Error::new(WorkflowError {
+        id: None,
+        error: "Internal Server Error".to_string(),
+    });
+    assert!(policy.should_retry(1, &retryable));
+    assert!(!policy.should_retry(3, &retryable));
+
+    let non_retryable = anyhow::Error::new(WorkflowError {
+        id: None,
+        error: "Forbidden".to_string(),
+    });
+    assert!(!policy.should_retry(1, &non_retryable));
+
+    let nondeterminism = anyhow::Error::new(NonDeterminismError {
+        position: 0,
+        expected: GuestToHost::Time,
+        actual: GuestToHost::Input,
+    });
+    assert!(!policy.should_retry(1, &nondeterminism));
+
+    let out_of_fuel = anyhow::Error::new(ResourceExhausted::Fuel(1_000));
+    assert!(!policy.should_retry(1, &out_of_fuel));
+
+    let timeout = anyhow::Error::new(ResourceExhausted::Timeout(Duration::from_secs(1)));
+    assert!(policy.should_retry(1, &timeout));
+}
+
+#[test]
+fn retry_backoff_edge_cases() {
+    // delays that do not fit a `Duration` are capped instead of panicking
+    let unbounded = RetryPolicy::default()
+        .with_backoff_coefficient(10.0)
+        .with_max_interval(Duration::MAX)
+        .with_jitter(0.0);
+    assert_eq!(unbounded.backoff(1_000), Duration::MAX);
+    assert_eq!(unbounded.backoff(usize::MAX), Duration::MAX);
+
+    // a coefficient set on the field directly cannot yield a negative delay
+    let negative = RetryPolicy {
+        backoff_coefficient: -2.0,
+        jitter: 0.0,
+        ..RetryPolicy::default()
+    };
+    assert_eq!(negative.backoff(2), negative.max_interval);
+
+    for coefficient in [-2.0, 0.5, f64::NAN, f64::INFINITY] {
+        let result = std::panic::catch_unwind(|| {
+            RetryPolicy::default().with_backoff_coefficient(coefficient)
+        });
+        assert!(result.is_err(), "coefficient {coefficient} was accepted");
+    }
+}
+
+#[test]
+fn limits_epoch_deadline() {
+    use crate::{
+        engine::EPOCH_TICK,
+        limits::{Limits, NO_DEADLINE},
+    };
+
+    assert_eq!(Limits::default().epoch_deadline(), NO_DEADLINE);
+    assert_eq!(
+        Limits::default()
+            .with_timeout(Duration::MAX)
+            .epoch_deadline(),
+        NO_DEADLINE
+    );
+    assert_eq!(
+        Limits::default()
+            .with_timeout(EPOCH_TICK * 10)
+            .epoch_deadline(),
+        10
+    );
+    assert_eq!(
+        Limits::default()
+            .with_timeout(Duration::from_nanos(1))
+            .epoch_deadline(),
+        1
+    );
+}
+
+/// Builds a workflow component whose `execute` export runs the given core instructions and
+/// then traps.
+fn component(body: &str) -> Result<Vec<u8>> {
+    Ok(wat::parse_str(format!(
+        r#"
+        (component
+            (core module $guest
+                (memory (export "memory") 1)
+                (table (export "table") 1 funcref)
+                (func (export "execute") (result i32) {body} unreachable)
+                (func (export "realloc") (param i32 i32 i32 i32) (result i32) i32.const 0)
+            )
+            (core instance $guest (instantiate $guest))
+            (type $workflow-error' (record (field "id" (option string)) (field "error" string)))
+            (export $workflow-error "workflow-error" (type $workflow-error'))
+            (func (export "execute") (result (result (list u8) (error $workflow-error)))
+                (canon lift
+                    (core func $guest "execute")
+                    (memory (core memory $guest "memory"))
+                    (realloc (core func $guest "realloc"))
+                )
+            )
+            (instance (export "component:workflow/abi"))
+            (instance (export "component:workflow/http"))
+        )
+        "#
+    ))?)
+}
+
+#[test]
+fn registry_compiles_each_binary_once() -> Result<()> {
+    use crate::registry::ComponentRegistry;
+
+    let engine = engine::new()?;
+    let binary = component("nop")?;
+    let code = |registry: &ComponentRegistry, binary: &[u8]| -> Result<_> {
+        Ok(registry
+            .instance_pre(binary)?
+            .instance_pre()
+            .component()
+            .image_range())
+    };
+
+    // the same binary is served from the cache, a different one is compiled
+    let registry = ComponentRegistry::new(&engine);
+    assert_eq!(code(&registry, &binary)?, code(&registry, &binary)?);
+    assert_ne!(code(&registry, &binary)?, code(&registry, &component("")?)?);
+
+    // a registry with the same cache directory loads the artifact instead of compiling it
+    let cache_dir = std::env::temp_dir().join(format!("registry-{}", uuid::Uuid::new_v4()));
+    code(
+        &ComponentRegistry::new(&engine).with_cache_dir(&cache_dir),
+        &binary,
+    )?;
+    let artifact = std::fs::read_dir(&cache_dir)?
+        .next()
+        .expect("artifact was written")?
+        .path();
+    let written = std::fs::metadata(&artifact)?.modified()?;
+    code(
+        &ComponentRegistry::new(&engine).with_cache_dir(&cache_dir),
+        &binary,
+    )?;
+    assert_eq!(std::fs::metadata(&artifact)?.modified()?, written);
+    std::fs::remove_dir_all(&cache_dir)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn limits_exhaust_the_guest() -> Result<()> {
+    use crate::{error::ResourceExhausted, executor::Executor, limits::Limits, workflow::Outcome};
+
+    let spin = "(loop br 0)";
+    let grow_memory = "(drop (memory.grow (i32.const 16)))";
+    let grow_table = "(drop (table.grow (ref.null func) (i32.const 16)))";
+
+    let executor = Executor::new(&engine::new()?);
+    for (body, limits, exhausted) in [
+        (
+            spin,
+            Limits::default().with_fuel(10_000),
+            ResourceExhausted::Fuel(10_000),
+        ),
+        (
+            grow_memory,
+            Limits::default().with_max_memory(1 << 20),
+            ResourceExhausted::Memory(1 << 20),
+        ),
+        (
+            grow_table,
+            Limits::default().with_max_table_elements(8),
+            ResourceExhausted::Table(8),
+        ),
+        (
+            spin,
+            Limits::default().with_timeout(Duration::from_millis(50)),
+            ResourceExhausted::Timeout(Duration::from_millis(50)),
+        ),
+    ] {
+        let mut workflow = executor.workflow(&component(body)?);
+        workflow.with_limits(limits);
+        let err = workflow.execute().await.unwrap_err();
+        assert_eq!(err.downcast_ref::<ResourceExhausted>(), Some(&exhausted));
+        assert_eq!(
+            workflow.state().executions()[0].outcome(),
+            Some(&Outcome::Exhausted(exhausted))
+        );
+    }
+
+    Ok(())
+}
+
+/// Routes a request to the capabilities of `state` the same way the guest bindings do.
+async fn call(
+    state: &mut State,
+    request: crate::guest::GuestToHost,
+) -> Result<crate::guest::HostToGuest> {
+    let capabilities = state.capabilities.clone();
+    capabilities.call(state, request).await
+}
+
+#[tokio::test]
+async fn timer_suspends_until_deadline() -> Result<()> {
+    use crate::{
+        error::Suspend,
+        guest::component::workflow::abi::{self, GuestToHost},
+    };
+
+    let request = GuestToHost::Sleep(abi::Duration {
+        secs: 3600,
+        nanos: 0,
+    });
+
+    let mut state = State::default();
+    state.start_execution()?;
+    let err = call(&mut state, request.clone()).await.unwrap_err();
+    let Some(Suspend::Timer(deadline)) = err.downcast_ref::<Suspend>().cloned() else {
+        panic!("expected the workflow to be suspended");
+    };
+
+    // replaying the sleep waits for the journaled deadline rather than a new one
+    state.start_execution()?;
+    let err = call(&mut state, request).await.unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<Suspend>(),
+        Some(&Suspend::Timer(deadline))
+    );
+
+    // a deadline in the past does not suspend
+    let mut state = State::default();
+    state.start_execution()?;
+    call(
+        &mut state,
+        GuestToHost::SleepUntil(abi::SystemTime {
+            tv_sec: 0,
+            tv_nsec: 0,
+        }),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn timers_follow_the_clock() -> Result<()> {
+    use crate::{
+        clock::{Clock, FixedClock, ManualClock},
+        error::Suspend,
+        guest::component::workflow::abi::{self, Content, GuestToHost},
+    };
+    use std::{sync::Arc, time::UNIX_EPOCH};
+
+    let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let mut state = State::default().with_clock(Arc::new(FixedClock(start)));
+    state.start_execution()?;
+    let message = call(&mut state, GuestToHost::Time).await?;
+    assert!(matches!(
+        message.content,
+        Content::Time(abi::SystemTime {
+            tv_sec: 1_700_000_000,
+            tv_nsec: 0
+        })
+    ));
+
+    let day = Duration::from_secs(24 * 60 * 60);
+    let request = GuestToHost::Sleep(abi::Duration {
+        secs: day.as_secs(),
+        nanos: 0,
+    });
+    let clock = Arc::new(ManualClock::new(start));
+    let mut state = State::default().with_clock(clock.clone());
+    state.start_execution()?;
+    let err = call(&mut state, request.clone()).await.unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<Suspend>(),
+        Some(&Suspend::Timer(start + day))
+    );
+
+    // the timer fires once the clock is advanced past its deadline
+    clock.advance(day);
+    state.start_execution()?;
+    call(&mut state, request).await?;
+
+    // an auto-advancing clock skips straight to the deadline
+    let clock = ManualClock::new(start).with_auto_advance();
+    tokio::time::timeout(Duration::from_secs(1), clock.sleep_until(start + 365 * day)).await?;
+    assert_eq!(clock.now(), start + 365 * day);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn signals_are_delivered_and_replayed() -> Result<()> {
+    use crate::{
+        error::Suspend,
+        executor::Executor,
+        guest::component::workflow::abi::{Content, GuestToHost},
+        journal::{JournalStore, SqliteJournalStore},
+    };
+    use std::sync::Arc;
+
+    let journal = Arc::new(SqliteJournalStore::open_in_memory()?);
+    let executor = Executor::new(&engine::new()?).with_journal(journal.clone());
+    let workflow = executor.workflow(&vec![]);
+    let request = GuestToHost::WaitForSignal("approved".to_string());
+
+    // without a pending signal the workflow is parked
+    let mut state = workflow.state().clone();
+    state.start_execution()?;
+    let err = call(&mut state, request.clone()).await.unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<Suspend>(),
+        Some(&Suspend::Signal("approved".to_string()))
+    );
+
+    executor.signal(&state.id, "approved", &true)?;
+    state.start_execution()?;
+    let message = call(&mut state, request.clone()).await?;
+    assert_eq!(message.content, Content::Signal(b"true".to_vec()));
+
+    // after a restart the delivered signal is replayed from the journal
+    let mut state = journal.load_state(&state.id)?.expect("state was persisted");
+    assert!(state.signals.take("approved").is_none());
+    state.start_execution()?;
+    let message = call(&mut state, request).await?;
+    assert_eq!(message.content, Content::Signal(b"true".to_vec()));
+
+    Ok(())
+}
+
+/// A journal whose message writes fail while `fail` is set.
+#[derive(Debug)]
+struct FailingJournal {
+    journal: crate::journal::SqliteJournalStore,
+    fail: std::sync::atomic::AtomicBool,
+}
+
+impl crate::journal::JournalStore for FailingJournal {
+    fn save_state(&self, state: &State) -> Result<()> {
+        self.journal.save_state(state)
+    }
+
+    fn save_execution(
+        &self,
+        state_id: &uuid::Uuid,
+        sequence: usize,
+        execution: &workflow::Execution,
+    ) -> Result<()> {
+        self.journal.save_execution(state_id, sequence, execution)
+    }
+
+    fn save_message(
+        &self,
+        execution_id: &uuid::Uuid,
+        position: usize,
+        log_message: &workflow::LogMessage,
+    ) -> Result<()> {
+        if self.fail.load(std::sync::atomic::Ordering::SeqCst) {
+            return Err(anyhow::anyhow!("disk full"));
+        }
+        self.journal
+            .save_message(execution_id, position, log_message)
+    }
+
+    fn truncate_log(&self, state_id: &uuid::Uuid, position: usize) -> Result<()> {
+        self.journal.truncate_log(state_id, position)
+    }
+
+    fn save_outcome(&self, execution_id: &uuid::Uuid, outcome: &workflow::Outcome) -> Result<()> {
+        self.journal.save_outcome(execution_id, outcome)
+    }
+
+    fn continue_as_new(&self, state: &State) -> Result<()> {
+        self.journal.continue_as_new(state)
+    }
+
+    fn save_signal(&self, state_id: &uuid::Uuid, signal: &crate::signal::Signal) -> Result<()> {
+        self.journal.save_signal(state_id, signal)
+    }
+
+    fn load_state(&self, id: &uuid::Uuid) -> Result<Option<State>> {
+        self.journal.load_state(id)
+    }
+}
+
+#[tokio::test]
+async fn signals_survive_a_failed_journal_write() -> Result<()> {
+    use crate::{
+        executor::Executor,
+        guest::component::workflow::abi::{Content, GuestToHost},
+        journal::{JournalStore, SqliteJournalStore},
+    };
+    use std::sync::{atomic::Ordering, Arc};
+
+    let journal = Arc::new(FailingJournal {
+        journal: SqliteJournalStore::open_in_memory()?,
+        fail: true.into(),
+    });
+    let executor = Executor::new(&engine::new()?).with_journal(journal.clone());
+    let workflow = executor.workflow(&vec![]);
+    let request = GuestToHost::WaitForSignal("approved".to_string());
+
+    let mut state = workflow.state().clone();
+    state.start_execution()?;
+    executor.signal(&state.id, "approved", &true)?;
+
+    // the delivery could not be journaled, so the signal is still pending, in the inbox as
+    // well as in the journal
+    call(&mut state, request.clone()).await.unwrap_err();
+    assert!(state.signals.take("approved").is_some());
+
+    journal.fail.store(false, Ordering::SeqCst);
+    let mut state = journal
+        .load_state(&state.id)?
+        .expect("state was persisted")
+        .with_journal(journal.clone());
+    state.start_execution()?;
+    let message = call(&mut state, request.clone()).await?;
+    assert_eq!(message.content, Content::Signal(b"true".to_vec()));
+
+    // once journaled the signal is consumed
+    let mut state = journal.load_state(&state.id)?.expect("state was persisted");
+    assert!(state.signals.take("approved").is_none());
+    state.start_execution()?;
+    assert_eq!(call(&mut state, request).await?, message);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn child_result_is_journaled() -> Result<()> {
+    use crate::{
+        error::CapabilityError,
+        executor::Executor,
+        guest::component::workflow::abi::{ChildWorkflow, Content, GuestToHost},
+        journal::SqliteJournalStore,
+    };
+    use std::sync::Arc;
+
+    let journal = Arc::new(SqliteJournalStore::open_in_memory()?);
+    let executor = Executor::new(&engine::new()?).with_journal(journal.clone());
+
+    // a child that completed before the executor restarted
+    let mut child = State::default().with_journal(journal.clone());
+    child.start_execution()?;
+    child.set_output(serde_json::json!("receipt"))?;
+
+    let mut state = executor.workflow(&vec![]).state().clone();
+    state.start_execution()?;
+
+    // invalid requests fail with a `CapabilityError`, so the workflow is not retried
+    for (request, reason) in [
+        (
+            GuestToHost::StartChild(ChildWorkflow {
+                component_name: "unknown".to_string(),
+                input: b"null".to_vec(),
+            }),
+            "component unknown is not registered",
+        ),
+        (
+            GuestToHost::StartChild(ChildWorkflow {
+                component_name: "unknown".to_string(),
+                input: b"{".to_vec(),
+            }),
+            "invalid child input: EOF while parsing an object at line 1 column 1",
+        ),
+        (
+            GuestToHost::AwaitChild("order".to_string()),
+            "invalid child id order: invalid character: found `o` at 0",
+        ),
+    ] {
+        let err = call(&mut state, request).await.unwrap_err();
+        let err = err
+            .downcast_ref::<CapabilityError>()
+            .expect("expected a CapabilityError");
+        assert_eq!(err.capability, "child");
+        assert_eq!(err.reason, reason);
+        assert!(!RetryPolicy::default().should_retry(1, &anyhow::Error::new(err.clone())));
+    }
+
+    let request = GuestToHost::AwaitChild(child.id.to_string());
+    let message = call(&mut state, request.clone()).await?;
+    assert_eq!(
+        message.content,
+        Content::ChildResult(Ok(b"\"receipt\"".to_vec()))
+    );
+
+    // replays return the journaled result
+    state.start_execution()?;
+    let replayed = call(&mut state, request).await?;
+    assert_eq!(replayed, message);
+
+    // a child that failed for good before the executor restarted is not run again
+    let mut failed = State::default()
+        .with_journal(journal.clone())
+        .with_component(Some("unknown".to_string()));
+    failed.start_execution()?;
+    failed.set_error("payment declined".to_string())?;
+    let child = executor.child(&failed.id)?;
+    assert_eq!(child.result(), Some(Err("payment declined".to_string())));
+    assert!(Arc::ptr_eq(&child, &executor.child(&failed.id)?));
+
+    let message = call(&mut state, GuestToHost::AwaitChild(failed.id.to_string())).await?;
+    assert_eq!(
+        message.content,
+        Content::ChildResult(Err("payment declined".to_string()))
+    );
+    // once the parent journaled the result the executor lets go of the child
+    assert!(!Arc::ptr_eq(&child, &executor.child(&failed.id)?));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retries_keep_signals_and_children_logged_after_a_failure() -> Result<()> {
+    use crate::{
+        executor::Executor,
+        guest::component::workflow::{
+            abi::{ChildWorkflow, Content, GuestToHost},
+            http::{Method, Request},
+        },
+        journal::{JournalStore, SqliteJournalStore},
+        policy::NetworkPolicy,
+    };
+    use std::sync::Arc;
+
+    let fetch = GuestToHost::HttpRequest(Request {
+        method: Method::Get,
+        body: None,
+        path: "https://example.org/".to_string(),
+        headers: vec![],
+        query: vec![],
+        timeout_ms: None,
+        redirect: None,
+        max_response_size: None,
+    });
+    let wait = GuestToHost::WaitForSignal("approved".to_string());
+    let start = GuestToHost::StartChild(ChildWorkflow {
+        component_name: "shipping".to_string(),
+        input: b"null".to_vec(),
+    });
+
+    let journal = Arc::new(SqliteJournalStore::open_in_memory()?);
+    let executor = Executor::new(&engine::new()?)
+        .with_journal(journal.clone())
+        .with_network_policy(NetworkPolicy::public().with_allowed_host("example.com"));
+    executor.register("shipping", vec![]);
+    let mut state = executor.workflow(&vec![]).state().clone();
+    state.start_execution()?;
+
+    // the guest fails on the denied request after it got a signal and started a child
+    executor.signal(&state.id, "approved", &true)?;
+    let failed = call(&mut state, fetch.clone()).await?;
+    let signal = call(&mut state, wait.clone()).await?;
+    let child = call(&mut state, start.clone()).await?;
+    state.set_failure(&failed.id)?;
+
+    // the retry drops what was logged after the request, but not the signal nor the child
+    state.start_execution()?;
+    call(&mut state, fetch).await?;
+    assert_eq!(state.log.len(), 1);
+    assert_eq!(state.detached()[0].message, child);
+    let loaded = journal.load_state(&state.id)?.expect("state was persisted");
+    assert_eq!(loaded.detached()[0].message, child);
+    assert!(loaded.signals.take("approved").is_some());
+
+    let redelivered = call(&mut state, wait).await?;
+    assert_eq!(redelivered, signal);
+    assert_eq!(redelivered.content, Content::Signal(b"true".to_vec()));
+    assert_eq!(call(&mut state, start).await?, child);
+    assert!(state.detached().is_empty());
+
+    let loaded = journal.load_state(&state.id)?.expect("state was persisted");
+    assert!(loaded.detached().is_empty());
+    assert!(loaded.signals.take("approved").is_none());
+    assert_eq!(loaded.log()[1].message, signal);
+    assert_eq!(loaded.log()[2].message, child);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn capabilities_can_be_replaced() -> Result<()> {
+    use crate::{
+        capability::{Capability, CapabilityFuture},
+        executor::Executor,
+        guest::{
+            component::workflow::abi::{Content, SystemTime},
+            GuestToHost, HostToGuest,
+        },
+    };
+
+    /// A clock that is stuck at the epoch.
+    struct Epoch;
+
+    impl Capability for Epoch {
+        fn name(&self) -> &str {
+            "time"
+        }
+
+        fn call<'a>(
+            &'a self,
+            _state: &'a mut State,
+            _request: &'a GuestToHost,
+        ) -> CapabilityFuture<'a> {
+            Box::pin(async move {
+                Ok(HostToGuest {
+                    id: uuid::Uuid::new_v4().into(),
+                    content: Content::Time(SystemTime {
+                        tv_sec: 0,
+                        tv_nsec: 0,
+                    }),
+                })
+            })
+        }
+    }
+
+    let executor = Executor::new(&engine::new()?).with_capability(Epoch);
+    let mut state = executor.workflow(&vec![]).state().clone();
+    state.start_execution()?;
+
+    let message = call(&mut state, GuestToHost::Time).await?;
+    assert_eq!(
+        message.content,
+        Content::Time(SystemTime {
+            tv_sec: 0,
+            tv_nsec: 0,
+        })
+    );
+
+    // replays are served from the journal without calling the capability again
+    state.start_execution()?;
+    assert_eq!(call(&mut state, GuestToHost::Time).await?, message);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_requests_do_not_panic() -> Result<()> {
+    use crate::{
+        error::CapabilityError,
+        guest::component::workflow::{
+            abi::{self, Content, GuestToHost, RandomType},
+            http::{Error, Header, Kind, Method, Request},
+        },
+    };
+
+    let mut state = State::default();
+    state.start_execution()?;
+
+    // invalid headers are reported to the guest
+    let message = call(
+        &mut state,
+        GuestToHost::HttpRequest(Request {
+            method: Method::Get,
+            body: None,
+            path: "http://localhost".to_string(),
+            headers: vec![Header {
+                key: "invalid header".to_string(),
+                value: "value".to_string(),
+            }],
+            query: vec![],
+            timeout_ms: None,
+            redirect: None,
+            max_response_size: None,
+        }),
+    )
+    .await?;
+    assert!(matches!(
+        message.content,
+        Content::HttpResponse(Err(Error {
+            kind: Some(Kind::Builder),
+            ..
+        }))
+    ));
+
+    // unsupported requests trap the guest with a typed error
+    let err = call(
+        &mut state,
+        GuestToHost::Random(RandomType::BytesType((1 << 20) + 1)),
+    )
+    .await
+    .unwrap_err();
+    assert!(err.downcast_ref::<CapabilityError>().is_some());
+    assert!(!RetryPolicy::default().should_retry(1, &err));
+
+    let err = call(
+        &mut state,
+        GuestToHost::Sleep(abi::Duration {
+            secs: u64::MAX,
+            nanos: 999_999_999,
+        }),
+    )
+    .await
+    .unwrap_err();
+    assert!(err.downcast_ref::<CapabilityError>().is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn random_values_match_the_requested_length() -> Result<()> {
+    use crate::guest::component::workflow::abi::{Content, GuestToHost, RandomType, Types};
+
+    let mut state = State::default();
+    state.start_execution()?;
+
+    let message = call(&mut state, GuestToHost::Random(RandomType::StringType(16))).await?;
+    assert!(matches!(
+        message.content,
+        Content::Value(Types::StringType(ref value))
+            if value.len() == 16 && value.chars().all(|c| c.is_ascii_alphanumeric())
+    ));
+
+    let message = call(&mut state, GuestToHost::Random(RandomType::BytesType(32))).await?;
+    assert!(matches!(
+        message.content,
+        Content::Value(Types::BytesType(ref value)) if value.len() == 32
+    ));
+
+    for (ty, reason) in [
+        (
+            RandomType::StringType(1 << 21),
+            "at most 1048576 random characters can be drawn at once",
+        ),
+        (
+            RandomType::BytesType(1 << 21),
+            "at most 1048576 random bytes can be drawn at once",
+        ),
+    ] {
+        let err = call(&mut state, GuestToHost::Random(ty)).await.unwrap_err();
+        let err = err
+            .downcast_ref::<crate::error::CapabilityError>()
+            .expect("expected a CapabilityError");
+        assert_eq!(err.reason, reason);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn logs_are_journaled() -> Result<()> {
+    use crate::guest::component::workflow::abi::{Field, GuestToHost, Level, LogRecord};
+
+    let request = GuestToHost::Log(Level::Info(LogRecord {
+        message: "hello".to_string(),
+        target: Some("workflow_example".to_string()),
+        fields: vec![Field {
+            key: "order".to_string(),
+            value: "42".to_string(),
+        }],
+    }));
+
+    let mut state = State::default();
+    state.start_execution()?;
+    let message = call(&mut state, request.clone()).await?;
+    assert_eq!(state.log.len(), 1);
+
+    // a replayed record is served from the journal instead of being logged again
+    state.start_execution()?;
+    assert_eq!(call(&mut state, request).await?, message);
+    assert_eq!(state.log.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retries_replace_what_was_logged_after_a_failure() -> Result<()> {
+    use crate::{
+        guest::component::workflow::abi::{Content, GuestToHost, HostToGuest, Level, LogRecord},
+        journal::{JournalStore, SqliteJournalStore},
+    };
+    use std::sync::Arc;
+
+    let respond = |id: &'static str| {
+        move || async move {
+            Ok(HostToGuest {
+                id: id.to_string(),
+                content: Content::Unit,
+            })
+        }
+    };
+    let log = |message: &str| {
+        GuestToHost::Log(Level::Error(LogRecord {
+            message: message.to_string(),
+            target: None,
+            fields: vec![],
+        }))
+    };
+
+    let journal = Arc::new(SqliteJournalStore::open_in_memory()?);
+    let mut state = State::default().with_journal(journal.clone());
+    state.start_execution()?;
+    state
+        .retrieve_or_else(&GuestToHost::Time, respond("failed"))
+        .await?;
+    call(&mut state, log("could not get location")).await?;
+    state.set_failure("failed")?;
+
+    // the retry takes another path after the re-executed call
+    state.start_execution()?;
+    state
+        .retrieve_or_else(&GuestToHost::Time, respond("retried"))
+        .await?;
+    call(&mut state, log("current location")).await?;
+    assert_eq!(state.log.len(), 2);
+    assert_eq!(state.log[1].request, log("current location"));
+
+    let loaded = journal.load_state(&state.id)?.expect("state was persisted");
+    assert_eq!(loaded.log.len(), 2);
+    assert_eq!(loaded.log[0].message.id, "retried");
+    assert_eq!(loaded.log[1].request, log("current location"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn http_request_options() -> Result<()> {
+    use crate::guest::component::workflow::{
+        abi::{Content, GuestToHost},
+        http::{Error, Header, Kind, Method, RedirectPolicy, Request},
+    };
+    use axum::{extract::RawQuery, http::HeaderMap, response::Redirect};
+    use std::future::IntoFuture;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let base = format!("http://{}", listener.local_addr()?);
+    tokio::spawn(
+        axum::serve(
+            listener,
+            Router::new()
+                .route(
+                    "/echo",
+                    axum::routing::put(|RawQuery(query)| async { query.unwrap_or_default() }),
+                )
+                .route("/redirect", get(|| async { Redirect::to("/target") }))
+                .route(
+                    "/see-other",
+                    axum::routing::put(|| async { Redirect::to("/method") }),
+                )
+                .route(
+                    "/method",
+                    axum::routing::any(
+                        |method: axum::http::Method, headers: HeaderMap, body: String| async move {
+                            format!(
+                                "{method} {body:?} {:?}",
+                                headers.get("content-type").is_some()
+                            )
+                        },
+                    ),
+                )
+                .route(
+                    "/slow-redirect",
+                    get(|| async {
+                        tokio::time::sleep(Duration::from_millis(300)).await;
+                        Redirect::to("/slow-redirect-target")
+                    }),
+                )
+                .route(
+                    "/slow-redirect-target",
+                    get(|| async {
+                        tokio::time::sleep(Duration::from_millis(300)).await;
+                        Redirect::to("/target")
+                    }),
+                )
+                .route("/target", get(|| async { "target" }))
+                .route("/large", get(|| async { "x".repeat(1024) }))
+                .route(
+                    "/slow",
+                    get(|| async {
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                        "slow"
+                    }),
+                ),
+        )
+        .into_future(),
+    );
+
+    let request = |method, path: &str| Request {
+        method,
+        body: None,
+        path: format!("{base}{path}"),
+        headers: vec![],
+        query: vec![],
+        timeout_ms: None,
+        redirect: None,
+        max_response_size: None,
+    };
+    let mut state = State::default();
+    state.start_execution()?;
+    let send = |request| {
+        let mut state = state.clone();
+        async move {
+            match call(&mut state, GuestToHost::HttpRequest(request))
+                .await?
+                .content
+            {
+                Content::HttpResponse(response) => Ok(response),
+                content => Err(anyhow::anyhow!("unexpected {content:?}")),
+            }
+        }
+    };
+
+    let response = send(Request {
+        query: vec![Header {
+            key: "order".to_string(),
+            value: "42".to_string(),
+        }],
+        ..request(Method::Put, "/echo")
+    })
+    .await?
+    .unwrap();
+    assert_eq!(response.body, b"order=42");
+
+    let response = send(request(Method::Get, "/redirect")).await?.unwrap();
+    assert_eq!(response.body, b"target");
+
+    let response = send(Request {
+        redirect: Some(RedirectPolicy::None),
+        ..request(Method::Get, "/redirect")
+    })
+    .await?
+    .unwrap();
+    assert_eq!(response.status, 303);
+
+    // a 303 turns a PUT into a GET without the body and the headers describing it
+    let response = send(Request {
+        body: Some(b"order=42".to_vec()),
+        headers: vec![Header {
+            key: "content-type".to_string(),
+            value: "application/x-www-form-urlencoded".to_string(),
+        }],
+        ..request(Method::Put, "/see-other")
+    })
+    .await?
+    .unwrap();
+    assert_eq!(response.body, b"GET \"\" false");
+
+    let err = send(Request {
+        max_response_size: Some(512),
+        ..request(Method::Get, "/large")
+    })
+    .await?
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        Error {
+            kind: Some(Kind::TooLarge),
+            ..
+        }
+    ));
+
+    let err = send(Request {
+        timeout_ms: Some(50),
+        ..request(Method::Get, "/slow")
+    })
+    .await?
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        Error {
+            kind: Some(Kind::Timeout),
+            ..
+        }
+    ));
+
+    // the timeout bounds the whole exchange, not every redirect on its own
+    let err = send(Request {
+        timeout_ms: Some(500),
+        ..request(Method::Get, "/slow-redirect")
+    })
+    .await?
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        Error {
+            kind: Some(Kind::Timeout),
+            ..
+        }
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn http_client_is_configured() -> Result<()> {
+    use crate::{
+        executor::Executor,
+        guest::component::workflow::{
+            abi::{Content, GuestToHost},
+            http::{Method, Request},
+        },
+        http::HttpConfig,
+    };
+    use axum::http::HeaderMap;
+    use std::future::IntoFuture;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let path = format!("http://{}/headers", listener.local_addr()?);
+    tokio::spawn(
+        axum::serve(
+            listener,
+            Router::new().route(
+                "/headers",
+                get(|headers: HeaderMap| async move {
+                    format!(
+                        "{} {}",
+                        headers["user-agent"].to_str().unwrap(),
+                        headers["x-team"].to_str().unwrap()
+                    )
+                }),
+            ),
+        )
+        .into_future(),
+    );
+
+    let config = HttpConfig::default()
+        .with_user_agent("workflows/1.0")
+        .with_default_header("x-team", "payments");
+    let executor = Executor::new(&engine::new()?).with_http(&config)?;
+    let mut state = executor.workflow(&vec![]).state().clone();
+    state.start_execution()?;
+
+    let message = call(
+        &mut state,
+        GuestToHost::HttpRequest(Request {
+            method: Method::Get,
+            body: None,
+            path,
+            headers: vec![],
+            query: vec![],
+            timeout_ms: None,
+            redirect: None,
+            max_response_size: None,
+        }),
+    )
+    .await?;
+    let Content::HttpResponse(Ok(response)) = message.content else {
+        panic!("expected a response");
+    };
+    assert_eq!(response.body, b"workflows/1.0 payments");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn network_policy_denies_requests() -> Result<()> {
+    use crate::{
+        executor::Executor,
+        guest::component::workflow::{
+            abi::{Content, GuestToHost},
+            http::{Error, Kind, Method, Request},
+        },
+        policy::NetworkPolicy,
+    };
+
+    let request = |method, path: &str| {
+        GuestToHost::HttpRequest(Request {
+            method,
+            body: None,
+            path: path.to_string(),
+            headers: vec![],
+            query: vec![],
+            timeout_ms: None,
+            redirect: None,
+            max_response_size: None,
+        })
+    };
+
+    let executor = Executor::new(&engine::new()?).with_network_policy(
+        NetworkPolicy::public()
+            .with_allowed_host("*.example.com")
+            .with_allowed_method(reqwest::Method::GET),
+    );
+    let mut state = executor.workflow(&vec![]).state().clone();
+    state.start_execution()?;
+
+    for (request, reason) in [
+        (
+            request(Method::Get, "http://api.example.com/"),
+            "scheme http is not allowed",
+        ),
+        (
+            request(Method::Get, "https://example.org/"),
+            "host example.org is not allowed",
+        ),
+        (
+            request(Method::Post, "https://api.example.com/"),
+            "method POST is not allowed",
+        ),
+        (
+            request(Method::Get, "https://169.254.169.254/latest/meta-data"),
+            "host 169.254.169.254 is not allowed",
+        ),
+    ] {
+        let Content::HttpResponse(Err(Error { kind, .. })) =
+            call(&mut state, request).await?.content
+        else {
+            panic!("expected the request to be denied");
+        };
+        assert_eq!(kind, Some(Kind::Denied(reason.to_string())));
+    }
+
+    // denied requests are journaled, and not retried once they fail the workflow
+    assert_eq!(state.log.len(), 4);
+    let err = state.failure(crate::guest::WorkflowError {
+        id: Some(state.log[1].message.id.clone()),
+        error: "the order service is unreachable".to_string(),
+    });
+    assert_eq!(
+        err.downcast_ref::<crate::error::Denied>(),
+        Some(&crate::error::Denied {
+            url: Some("https://example.org/".to_string()),
+            reason: "host example.org is not allowed".to_string(),
+        })
+    );
+    assert!(err.downcast_ref::<crate::guest::WorkflowError>().is_some());
+    assert!(!state.log[1].success);
+    assert!(!RetryPolicy::default().should_retry(1, &err));
+
+    // the metadata endpoint is denied by its network even if plain HTTP is allowed, also when
+    // it is reached through an IPv4-mapped or NAT64 address
+    let policy = NetworkPolicy::public().with_allowed_scheme("http");
+    for (url, address) in [
+        ("http://169.254.169.254/", "169.254.169.254"),
+        ("http://[::ffff:169.254.169.254]/", "::ffff:169.254.169.254"),
+        ("http://[64:ff9b::a9fe:a9fe]/", "64:ff9b::a9fe:a9fe"),
+        ("http://100.64.0.1/", "100.64.0.1"),
+        ("http://192.0.0.8/", "192.0.0.8"),
+        ("http://198.19.0.1/", "198.19.0.1"),
+    ] {
+        assert_eq!(
+            policy.check(&reqwest::Method::GET, &url.parse()?).await,
+            Err(format!("address {address} is in a denied network"))
+        );
+    }
+    assert_eq!(
+        policy
+            .check(&reqwest::Method::GET, &"http://93.184.215.14/".parse()?)
+            .await,
+        Ok(())
+    );
+
+    // the resolver enforces the networks, so a host resolving to a denied address is never
+    // connected to, even if it resolved to an allowed one when the request was checked
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://localhost:{}/", listener.local_addr()?.port());
+    let policy = std::sync::Arc::new(
+        NetworkPolicy::default()
+            .with_denied_network("127.0.0.0/8".parse()?)
+            .with_denied_network("::1/128".parse()?),
+    );
+    let err = crate::http::HttpConfig::default()
+        .build()?
+        .client(Some(&policy))?
+        .get(url)
+        .send()
+        .await
+        .expect_err("localhost should be denied");
+    let reason = crate::http::address_denied(&err).expect("the address should be denied");
+    assert!(reason.ends_with("is in a denied network"), "{reason}");
+    assert!(
+        tokio::time::timeout(Duration::from_millis(100), listener.accept())
+            .await
+            .is_err(),
+        "nothing should connect to a denied address"
+    );
+
+    // the workflows of a policy share one client, so they share its connection pool
+    use axum::extract::ConnectInfo;
+    use std::{collections::HashSet, future::IntoFuture, net::SocketAddr, sync::Mutex};
+
+    let peers = std::sync::Arc::new(Mutex::new(HashSet::new()));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}/", listener.local_addr()?);
+    tokio::spawn(
+        axum::serve(
+            listener,
+            Router::new()
+                .route(
+                    "/",
+                    get({
+                        let peers = peers.clone();
+                        |ConnectInfo(peer): ConnectInfo<SocketAddr>| async move {
+                            peers.lock().unwrap().insert(peer);
+                        }
+                    }),
+                )
+                .into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .into_future(),
+    );
+    let http = crate::http::HttpConfig::default().build()?;
+    let policy =
+        std::sync::Arc::new(NetworkPolicy::default().with_denied_network("10.0.0.0/8".parse()?));
+    for _ in 0..3 {
+        let response = http.client(Some(&policy))?.get(&url).send().await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        response.bytes().await?;
+    }
+    assert_eq!(peers.lock().unwrap().len(), 1);
+
+    Ok(())
+}
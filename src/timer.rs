@@ -0,0 +1,89 @@
+use crate::{
+    capability::{Capability, CapabilityFuture, Journaling},
+    error::{CapabilityError, Suspend},
+    guest::{
+        component::workflow::abi::{Content, SystemTime},
+        GuestToHost, HostToGuest,
+    },
+    workflow::State,
+};
+use anyhow::{anyhow, Result};
+use std::time::{Duration, UNIX_EPOCH};
+use uuid::Uuid;
+
+/// Parks the workflow until a deadline has passed.
+///
+/// The deadline is journaled by the timer itself, as every replay has to check it again.
+pub struct Timer;
+
+impl Capability for Timer {
+    fn name(&self) -> &str {
+        "timer"
+    }
+
+    fn journaling(&self) -> Journaling {
+        Journaling::Unjournaled
+    }
+
+    fn call<'a>(&'a self, state: &'a mut State, request: &'a GuestToHost) -> CapabilityFuture<'a> {
+        Box::pin(sleep(state, request))
+    }
+}
+
+async fn sleep(state: &mut State, request: &GuestToHost) -> Result<HostToGuest> {
+    let out_of_range = || CapabilityError::new("timer", request, "deadline is out of range");
+    let message = match request {
+        // The deadline is journaled on the first call so that replays wait for the same instant.
+        GuestToHost::Sleep(duration) => {
+            let clock = state.clock.clone();
+            let duration = Duration::from_secs(duration.secs)
+                .checked_add(Duration::from_nanos(duration.nanos.into()))
+                .ok_or_else(out_of_range)?;
+            state
+                .retrieve_or_else(request, || async {
+                    let deadline = clock.now().checked_add(duration).ok_or_else(out_of_range)?;
+                    Ok(HostToGuest {
+                        id: Uuid::new_v4().into(),
+                        content: Content::Timer(to_system_time(deadline)?),
+                    })
+                })
+                .await?
+        }
+        GuestToHost::SleepUntil(deadline) => {
+            state
+                .retrieve_or_else(request, || async {
+                    Ok(HostToGuest {
+                        id: Uuid::new_v4().into(),
+                        content: Content::Timer(*deadline),
+                    })
+                })
+                .await?
+        }
+        _ => return Err(CapabilityError::new("timer", request, "unsupported request").into()),
+    };
+
+    match &message.content {
+        Content::Timer(deadline) => {
+            let deadline = from_system_time(deadline).ok_or_else(out_of_range)?;
+            if state.clock.now() < deadline {
+                return Err(Suspend::Timer(deadline).into());
+            }
+            Ok(message)
+        }
+        content => Err(anyhow!("journaled {content:?} is not a timer deadline")),
+    }
+}
+
+fn to_system_time(time: std::time::SystemTime) -> Result<SystemTime> {
+    let duration_since_epoch = time.duration_since(UNIX_EPOCH)?;
+    Ok(SystemTime {
+        tv_sec: duration_since_epoch.as_secs(),
+        tv_nsec: duration_since_epoch.subsec_nanos(),
+    })
+}
+
+fn from_system_time(time: &SystemTime) -> Option<std::time::SystemTime> {
+    UNIX_EPOCH
+        .checked_add(Duration::from_secs(time.tv_sec))?
+        .checked_add(Duration::from_nanos(time.tv_nsec.into()))
+}
@@ -1,30 +1,51 @@
-use crate::guest::{self, component::workflow::abi::HostToGuest};
-use anyhow::Result;
-use rand::{thread_rng, SeedableRng};
+use crate::{
+    capability::Capabilities,
+    clock::{Clock, SystemClock},
+    error::{ContinueAsNew, Denied, NonDeterminismError, ResourceExhausted, Suspend},
+    executor::Executor,
+    guest::{
+        component::workflow::{
+            abi::{Content, HostToGuest},
+            http::{Error, Kind},
+        },
+        GuestToHost, WorkflowError,
+    },
+    journal::JournalStore,
+    limits::Limits,
+    policy::NetworkPolicy,
+    registry::ComponentRegistry,
+    retry::RetryPolicy,
+    signal::{Signal, Signals},
+};
+use anyhow::{anyhow, Result};
+use rand::{thread_rng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 use std::{
     future::Future,
     sync::{Arc, Mutex},
     time::SystemTime,
 };
 use uuid::Uuid;
-use wasmtime::{
-    component::{Component, Linker},
-    Engine, Store,
-};
+use wasmtime::{Store, Trap};
 
 pub struct Workflow {
-    engine: Engine,
+    registry: ComponentRegistry,
     state: State,
     binary: Vec<u8>,
+    retry_policy: RetryPolicy,
+    limits: Limits,
 }
 
-#[allow(dead_code)]
 impl Workflow {
-    pub fn new(engine: &Engine, binary: &Vec<u8>) -> Self {
+    /// Creates a `Workflow` for the given component binary, compiled by the shared
+    /// `ComponentRegistry` so the binary is compiled only once across workflows.
+    pub fn new(registry: &ComponentRegistry, binary: &Vec<u8>) -> Self {
         Self {
-            engine: engine.to_owned(),
+            registry: registry.to_owned(),
             state: State::default(),
             binary: binary.to_owned(),
+            retry_policy: RetryPolicy::default(),
+            limits: Limits::default(),
         }
     }
 
@@ -33,168 +54,736 @@ impl Workflow {
         self
     }
 
-    pub async fn execute(&mut self) -> Result<()> {
-        self.state.start_execution();
+    /// Returns the `State` of this workflow.
+    pub fn state(&self) -> &State {
+        &self.state
+    }
 
-        let mut store = Store::new(&self.engine, self.state.clone());
-        store.set_fuel(u64::MAX)?;
-        store.fuel_async_yield_interval(Some(10000))?;
+    /// Sets the policy used by `run_to_completion` to retry failed executions.
+    pub fn with_retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the resources each execution of the workflow is allowed to consume.
+    pub fn with_limits(&mut self, limits: Limits) -> &mut Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Restricts the endpoints the workflow may reach over HTTP.
+    pub fn with_network_policy(&mut self, policy: NetworkPolicy) -> &mut Self {
+        self.state.network_policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Uses the given `Clock` as the source of time of the workflow, e.g. a `ManualClock` to
+    /// control time in tests.
+    pub fn with_clock(&mut self, clock: Arc<dyn Clock>) -> &mut Self {
+        self.state.clock = clock;
+        self
+    }
 
-        let mut linker = Linker::new(&self.engine);
-        guest::Workflow::add_to_linker(&mut linker, |state: &mut State| state)?;
-        let component = Component::from_binary(&self.engine, &self.binary)?;
-        let workflow = guest::Workflow::instantiate_async(&mut store, &component, &linker).await?;
-
-        Ok(workflow
-            .call_execute(&mut store)
-            .await?
-            .inspect(|_| self.state = store.data().clone())
-            .inspect_err(|err| {
-                if let Some(id) = &err.id {
-                    store.data_mut().set_failure(id);
-                    self.state = store.data().clone()
+    /// Seeds the random number generator of the workflow, e.g. to reproduce a failed run.
+    pub fn with_seed(&mut self, seed: u64) -> &mut Self {
+        self.state.reseed(seed);
+        self
+    }
+
+    /// Sets the input the workflow is started with.
+    ///
+    /// The input is serialized to JSON and delivered to the guest when it calls `workflow::input`.
+    pub fn with_input<T: Serialize>(&mut self, input: &T) -> Result<&mut Self> {
+        self.state.input = serde_json::to_value(input)?;
+        Ok(self)
+    }
+
+    /// Reconstructs a `Workflow` from the journal by the `State::id` it was persisted with.
+    pub fn load(
+        registry: &ComponentRegistry,
+        binary: &Vec<u8>,
+        journal: Arc<dyn JournalStore>,
+        id: &Uuid,
+    ) -> Result<Self> {
+        let state = journal
+            .load_state(id)?
+            .ok_or_else(|| anyhow!("workflow state {id} not found in journal"))?
+            .with_journal(journal);
+
+        Ok(Self {
+            registry: registry.to_owned(),
+            state,
+            binary: binary.to_owned(),
+            retry_policy: RetryPolicy::default(),
+            limits: Limits::default(),
+        })
+    }
+
+    /// Executes the workflow, returning its decoded output on success.
+    ///
+    /// The output is also stored on the `State` as the workflow's final result.
+    pub async fn execute(&mut self) -> Result<serde_json::Value> {
+        self.state.start_execution()?;
+
+        let result = self.instantiate_and_execute().await;
+        self.state.finish_execution(match &result {
+            Ok(_) => Outcome::Succeeded,
+            Err(err) => {
+                if let Some(suspend) = err.downcast_ref::<Suspend>() {
+                    Outcome::Suspended(suspend.to_owned())
+                } else if let Some(exhausted) = err.downcast_ref::<ResourceExhausted>() {
+                    Outcome::Exhausted(exhausted.to_owned())
+                } else if err.downcast_ref::<ContinueAsNew>().is_some() {
+                    Outcome::ContinuedAsNew
+                } else {
+                    Outcome::Failed(err.root_cause().to_string())
                 }
-            })?)
+            }
+        })?;
+
+        let output = result?;
+        self.state.set_output(output.clone())?;
+
+        Ok(output)
+    }
+
+    /// Executes the workflow until it succeeds, retrying failed executions according to the
+    /// `RetryPolicy`, resuming suspended executions once they are able to make progress and
+    /// starting a new run when the guest continues as new.
+    ///
+    /// Every attempt is recorded as an `Execution` on the `State`, and the error the workflow
+    /// fails with once it is not retried anymore is stored as well.
+    pub async fn run_to_completion(&mut self) -> Result<serde_json::Value> {
+        loop {
+            match self.execute().await {
+                Ok(output) => return Ok(output),
+                Err(err) => {
+                    if let Some(ContinueAsNew { input }) = err.downcast_ref::<ContinueAsNew>() {
+                        self.state.continue_as_new(input.to_owned())?;
+                        continue;
+                    }
+                    match err.downcast_ref::<Suspend>() {
+                        Some(Suspend::Timer(deadline)) => {
+                            self.state.clock.clone().sleep_until(*deadline).await
+                        }
+                        Some(Suspend::Signal(name)) => self.state.signals.wait(name).await,
+                        Some(Suspend::Child(id)) => {
+                            let executor = self.state.executor.clone().ok_or_else(|| {
+                                anyhow!("child workflows can only be awaited by an Executor")
+                            })?;
+                            executor.child(id)?.wait().await
+                        }
+                        None => {
+                            let attempt = self.state.failed_executions();
+                            if !self.retry_policy.should_retry(attempt, &err) {
+                                // a journal write failure must not mask the workflow error
+                                _ = self.state.set_error(err.root_cause().to_string());
+                                return Err(err);
+                            }
+                            tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn instantiate_and_execute(&mut self) -> Result<serde_json::Value> {
+        let instance_pre = self.registry.instance_pre(&self.binary)?;
+
+        let mut state = self.state.clone();
+        state.limits = self.limits.clone();
+
+        let mut store = Store::new(self.registry.engine(), state);
+        store.limiter(|state| &mut state.limits);
+        store.set_fuel(self.limits.fuel.unwrap_or(u64::MAX))?;
+        store.fuel_async_yield_interval(Some(10000))?;
+        store.set_epoch_deadline(self.limits.epoch_deadline());
+
+        let workflow = instance_pre.instantiate_async(&mut store).await?;
+
+        let result = workflow.call_execute(&mut store).await;
+        self.state = store.data().clone();
+
+        // surface traps caused by the configured limits as distinct errors
+        let result = result.map_err(|err| match err.downcast_ref::<Trap>() {
+            Some(Trap::OutOfFuel) => match self.limits.fuel {
+                Some(fuel) => ResourceExhausted::Fuel(fuel).into(),
+                None => err,
+            },
+            Some(Trap::Interrupt) => match self.limits.timeout {
+                Some(timeout) => ResourceExhausted::Timeout(timeout).into(),
+                None => err,
+            },
+            _ => err,
+        });
+
+        let output = result?.map_err(|err| self.state.failure(err))?;
+
+        Ok(serde_json::from_slice(&output)?)
     }
 }
 
 /// Represents the state of a workflow execution.
 #[derive(Clone, Debug)]
-#[allow(unused)]
 pub struct State {
     /// Unique identifier for the state.
     pub id: Uuid,
     /// Time when the state was created.
-    created: SystemTime,
+    pub(crate) created: SystemTime,
+    /// Name of the registered component this workflow runs, if it was started as a child.
+    pub(crate) component: Option<String>,
+    /// Input the workflow was started with.
+    pub(crate) input: serde_json::Value,
+    /// Final result of the workflow once it has completed successfully.
+    pub(crate) output: Option<serde_json::Value>,
+    /// Error the workflow failed with once it is not retried anymore.
+    pub(crate) error: Option<String>,
     /// List of executions associated with this state.
-    executions: Vec<Execution>,
+    pub(crate) executions: Vec<Execution>,
+    /// Journal of the host calls of the guest, shared by all executions.
+    pub(crate) log: Vec<LogMessage>,
+    /// Messages that started child workflows and were dropped from the log by a retry.
+    pub(crate) detached: Vec<LogMessage>,
+    /// Seed of the random number generator, persisted so a run can be reproduced.
+    pub(crate) seed: u64,
     /// Random number generator.
-    pub rng: Arc<Mutex<::rand::rngs::StdRng>>,
+    pub(crate) rng: Arc<Mutex<::rand::rngs::StdRng>>,
+    /// Signals sent to the workflow that have not been delivered yet.
+    pub(crate) signals: Arc<Signals>,
+    /// Durable store the journal is persisted to, if any.
+    pub(crate) journal: Option<Arc<dyn JournalStore>>,
+    /// Executor the workflow was created by, used to start child workflows.
+    pub(crate) executor: Option<Executor>,
+    /// Resource limits enforced on the current execution.
+    pub(crate) limits: Limits,
+    /// Host functions the guest can call.
+    pub(crate) capabilities: Capabilities,
+    /// Endpoints the guest may reach over HTTP, or `None` to allow all.
+    pub(crate) network_policy: Option<Arc<NetworkPolicy>>,
+    /// Source of the time told to the guest and of timer deadlines.
+    pub(crate) clock: Arc<dyn Clock>,
 }
 
 impl Default for State {
     /// Creates a new `State` with default values.
     fn default() -> Self {
+        let seed = thread_rng().gen();
         Self {
             id: Uuid::new_v4(),
             created: SystemTime::now(),
+            component: None,
+            input: serde_json::Value::Null,
+            output: None,
+            error: None,
             executions: vec![],
-            rng: Arc::new(Mutex::new(
-                ::rand::rngs::StdRng::from_rng(thread_rng()).expect("rand should not fail"),
-            )),
+            log: vec![],
+            detached: vec![],
+            seed,
+            rng: Arc::new(Mutex::new(::rand::rngs::StdRng::seed_from_u64(seed))),
+            signals: Arc::new(Signals::default()),
+            journal: None,
+            executor: None,
+            limits: Limits::default(),
+            capabilities: Capabilities::default(),
+            network_policy: None,
+            clock: Arc::new(SystemClock),
         }
     }
 }
 
-#[allow(dead_code)]
 impl State {
-    pub fn with_rng(mut self, rng: ::rand::rngs::StdRng) -> Self {
-        self.rng = Arc::new(Mutex::new(rng));
+    /// Seeds the random number generator, so the guest draws the same random values as any
+    /// other run with this seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.reseed(seed);
         self
     }
 
-    /// Starts a new execution and returns a new `State` with the added execution.
-    pub fn start_execution(&mut self) {
-        if let Some(execution) = self.executions.last() {
-            self.executions.push(Execution::new(&execution.log))
-        } else {
-            self.executions.push(Execution::new(&[]))
-        };
+    /// Seeds the random number generator, like `with_seed`.
+    pub fn with_rng(self, seed: u64) -> Self {
+        self.with_seed(seed)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = Arc::new(Mutex::new(::rand::rngs::StdRng::seed_from_u64(seed)));
+    }
+
+    /// Returns the seed of the random number generator.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Sets the id of the workflow, e.g. when restoring it from a `JournalStore`.
+    pub fn with_id(mut self, id: Uuid) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Returns the time the workflow was created.
+    pub fn created(&self) -> SystemTime {
+        self.created
+    }
+
+    /// Sets the time the workflow was created, e.g. when restoring it from a `JournalStore`.
+    pub fn with_created(mut self, created: SystemTime) -> Self {
+        self.created = created;
+        self
+    }
+
+    /// Returns the name of the registered component, if the workflow was started as a child.
+    pub fn component(&self) -> Option<&str> {
+        self.component.as_deref()
+    }
+
+    /// Sets the name of the registered component the workflow runs.
+    pub fn with_component(mut self, component: Option<String>) -> Self {
+        self.component = component;
+        self
+    }
+
+    /// Returns the executions of the current run, oldest first.
+    pub fn executions(&self) -> &[Execution] {
+        &self.executions
+    }
+
+    /// Sets the executions of the current run, e.g. when restoring it from a `JournalStore`.
+    pub fn with_executions(mut self, executions: Vec<Execution>) -> Self {
+        self.executions = executions;
+        self
+    }
+
+    /// Returns the journal of the host calls of the current run, by position.
+    pub fn log(&self) -> &[LogMessage] {
+        &self.log
+    }
+
+    /// Sets the journal of the host calls, e.g. when restoring it from a `JournalStore`.
+    ///
+    /// Replayed draws are served from the journal, so the random number generator is advanced
+    /// past the values drawn by the journaled executions; otherwise the guest would draw the
+    /// same values again once it runs past the replayed part. Restore the seed with
+    /// `with_seed` first.
+    pub fn with_log(mut self, log: Vec<LogMessage>) -> Result<Self> {
+        self.reseed(self.seed);
+        {
+            let mut rng = self.rng.lock().unwrap();
+            for log_message in &log {
+                if let GuestToHost::Random(ty) = &log_message.request {
+                    crate::rand::draw(&mut rng, ty).map_err(|reason| anyhow!(reason))?;
+                }
+            }
+        }
+        self.log = log;
+        Ok(self)
+    }
+
+    /// Returns the messages that started child workflows and were dropped from the log by a
+    /// retry, so the children are reattached instead of started again.
+    pub fn detached(&self) -> &[LogMessage] {
+        &self.detached
+    }
+
+    /// Sets the detached messages, e.g. when restoring the workflow from a `JournalStore`.
+    pub fn with_detached(mut self, detached: Vec<LogMessage>) -> Self {
+        self.detached = detached;
+        self
+    }
+
+    /// Removes and returns the detached message answering `request`, if any.
+    pub(crate) fn reattach(&mut self, request: &GuestToHost) -> Option<HostToGuest> {
+        let index = self
+            .detached
+            .iter()
+            .position(|log_message| log_message.request == *request)?;
+        Some(self.detached.remove(index).message)
+    }
+
+    /// Queues a signal that has not been delivered yet, e.g. when restoring the workflow from
+    /// a `JournalStore`.
+    pub fn with_signal(self, signal: Signal) -> Self {
+        self.signals.push(signal);
+        self
+    }
+
+    /// Sets the input the workflow is started with.
+    pub fn with_input(mut self, input: serde_json::Value) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Returns the input the workflow was started with.
+    pub fn input(&self) -> &serde_json::Value {
+        &self.input
+    }
+
+    /// Sets the final result of the workflow, e.g. when restoring it from a `JournalStore`.
+    pub fn with_output(mut self, output: Option<serde_json::Value>) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Returns the final result of the workflow, if it has completed.
+    pub fn output(&self) -> Option<&serde_json::Value> {
+        self.output.as_ref()
+    }
+
+    /// Records the final result of the workflow.
+    pub(crate) fn set_output(&mut self, output: serde_json::Value) -> Result<()> {
+        self.output = Some(output);
+        if let Some(journal) = &self.journal {
+            journal.save_state(self)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the error the workflow failed with, e.g. when restoring it from a `JournalStore`.
+    pub fn with_error(mut self, error: Option<String>) -> Self {
+        self.error = error;
+        self
+    }
+
+    /// Returns the error the workflow failed with, if it failed and is not retried anymore.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Records the error the workflow failed with once it is not retried anymore.
+    pub(crate) fn set_error(&mut self, error: String) -> Result<()> {
+        self.error = Some(error);
+        if let Some(journal) = &self.journal {
+            journal.save_state(self)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of executions that have failed.
+    pub fn failed_executions(&self) -> usize {
+        self.executions
+            .iter()
+            .filter(|execution| {
+                matches!(
+                    execution.outcome,
+                    Some(Outcome::Failed(_) | Outcome::Exhausted(_))
+                )
+            })
+            .count()
+    }
+
+    /// Records the outcome of the current execution.
+    fn finish_execution(&mut self, outcome: Outcome) -> Result<()> {
+        let journal = self.journal.clone();
+        let execution = self.execution()?;
+        if let Some(journal) = journal {
+            journal.save_outcome(&execution.id, &outcome)?;
+        }
+        execution.outcome = Some(outcome);
+        Ok(())
+    }
+
+    /// Uses the given `Clock` as the source of time of the workflow.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Persists every journal entry of this `State` to the given store as it is appended.
+    pub fn with_journal(mut self, journal: Arc<dyn JournalStore>) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// Starts a new run of the workflow with the given input.
+    ///
+    /// The executions, the log and the detached messages of the previous run are dropped, from
+    /// the journal as well,
+    /// and the random number generator is reseeded so the new run draws new values. The id
+    /// and undelivered signals of the workflow are kept.
+    pub fn continue_as_new(&mut self, input: serde_json::Value) -> Result<()> {
+        let seed = self.rng.lock().unwrap().gen();
+        self.reseed(seed);
+        self.input = input;
+        self.output = None;
+        self.error = None;
+        self.executions.clear();
+        self.log.clear();
+        self.detached.clear();
+        if let Some(journal) = &self.journal {
+            journal.continue_as_new(self)?;
+        }
+        Ok(())
+    }
+
+    /// Starts a new execution, which replays the log from its beginning.
+    pub fn start_execution(&mut self) -> Result<()> {
+        self.executions.push(Execution::new());
+
+        if let (Some(journal), Some(execution)) = (&self.journal, self.executions.last()) {
+            journal.save_state(self)?;
+            journal.save_execution(&self.id, self.executions.len() - 1, execution)?;
+        }
+
+        Ok(())
     }
 }
 
 /// Represents a single execution within a workflow state.
 #[derive(Clone, Debug)]
-#[allow(unused)]
 pub struct Execution {
     /// Unique identifier for the execution.
-    id: Uuid,
+    pub(crate) id: Uuid,
     /// Time when the execution was created.
-    created: SystemTime,
-    /// Current position in the execution log.
-    position: usize,
-    /// Log of messages for this execution.
-    log: Vec<LogMessage>,
+    pub(crate) created: SystemTime,
+    /// Current position in the log of the `State`.
+    pub(crate) position: usize,
+    /// Outcome of the execution once it has finished.
+    pub(crate) outcome: Option<Outcome>,
 }
 
 impl Execution {
-    /// Creates a new `Execution` with the given log.
-    fn new(log: &[LogMessage]) -> Self {
+    /// Creates a new `Execution` at the start of the log.
+    fn new() -> Self {
+        Self::restore(Uuid::new_v4(), SystemTime::now(), None)
+    }
+
+    /// Recreates a persisted `Execution`, e.g. when restoring it from a `JournalStore`.
+    pub fn restore(id: Uuid, created: SystemTime, outcome: Option<Outcome>) -> Self {
         Self {
-            id: Uuid::new_v4(),
-            created: SystemTime::now(),
+            id,
+            created,
             position: 0,
-            log: log.to_owned(),
+            outcome,
         }
     }
+
+    /// Returns the unique identifier of the execution.
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Returns the time the execution was started.
+    pub fn created(&self) -> SystemTime {
+        self.created
+    }
+
+    /// Returns the outcome of the execution, or `None` while it is running.
+    pub fn outcome(&self) -> Option<&Outcome> {
+        self.outcome.as_ref()
+    }
+}
+
+/// The result of a single `Execution` of a workflow.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Outcome {
+    /// The workflow ran to completion.
+    Succeeded,
+    /// The workflow failed with the given reason.
+    Failed(String),
+    /// The workflow was parked and will be resumed by replay.
+    Suspended(Suspend),
+    /// The workflow exceeded one of its resource limits.
+    Exhausted(ResourceExhausted),
+    /// The workflow finished its run to start again with a new input.
+    ContinuedAsNew,
 }
 
 /// Represents a log message within an execution.
 #[derive(Clone, Debug)]
-#[allow(unused)]
 pub struct LogMessage {
     /// Time when the log message was created.
-    created: SystemTime,
+    pub(crate) created: SystemTime,
     /// Indicates whether the operation was successful.
-    success: bool,
+    pub(crate) success: bool,
+    /// The guest request that produced this message.
+    pub(crate) request: GuestToHost,
     /// The actual message content.
-    message: HostToGuest,
+    pub(crate) message: HostToGuest,
 }
 
 impl LogMessage {
-    /// Creates a new `LogMessage` with the given success status and message.
-    fn new(success: bool, message: HostToGuest) -> Self {
+    /// Creates a new `LogMessage` with the given success status, originating request and message.
+    fn new(success: bool, request: GuestToHost, message: HostToGuest) -> Self {
+        Self::restore(SystemTime::now(), success, request, message)
+    }
+
+    /// Recreates a persisted `LogMessage`, e.g. when restoring it from a `JournalStore`.
+    pub fn restore(
+        created: SystemTime,
+        success: bool,
+        request: GuestToHost,
+        message: HostToGuest,
+    ) -> Self {
         Self {
-            created: SystemTime::now(),
+            created,
             success,
+            request,
             message,
         }
     }
+
+    /// Returns the time the message was recorded.
+    pub fn created(&self) -> SystemTime {
+        self.created
+    }
+
+    /// Returns whether the host call succeeded.
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the guest request that produced this message.
+    pub fn request(&self) -> &GuestToHost {
+        &self.request
+    }
+
+    /// Returns the response of the host.
+    pub fn message(&self) -> &HostToGuest {
+        &self.message
+    }
 }
 
 impl State {
     /// Retrieves a message from the current execution log or generates a new one using the provided function.
-    pub async fn retrieve_or_else<F, T>(&mut self, f: F) -> Result<HostToGuest>
+    ///
+    /// When replaying, the journaled request at the current position must match `request`,
+    /// otherwise a `NonDeterminismError` is returned.
+    pub async fn retrieve_or_else<F, T>(
+        &mut self,
+        request: &GuestToHost,
+        f: F,
+    ) -> Result<HostToGuest>
     where
         F: Fn() -> T,
         T: Future<Output = Result<HostToGuest>>,
     {
-        let execution = self.execution();
+        if let Some(message) = self.replay(request)? {
+            return Ok(message);
+        }
+        let message = f().await?;
+        self.record(request, message)
+    }
 
-        let message = match execution.log.get_mut(execution.position) {
-            Some(log_message) => {
-                if log_message.success {
-                    log_message.message.to_owned()
-                } else {
-                    *log_message = LogMessage::new(true, f().await?);
-                    log_message.message.to_owned()
-                }
+    /// Returns the successful message journaled at the current position and advances past it,
+    /// or `None` if a new message has to be generated for `request`.
+    pub(crate) fn replay(&mut self, request: &GuestToHost) -> Result<Option<HostToGuest>> {
+        let position = self.execution()?.position;
+
+        match self.log.get(position) {
+            Some(log_message) if log_message.request != *request => Err(NonDeterminismError {
+                position,
+                expected: log_message.request.to_owned(),
+                actual: request.to_owned(),
             }
-            None => {
-                let log_message = LogMessage::new(true, f().await?);
-                execution.log.push(log_message.to_owned());
-                log_message.message
+            .into()),
+            Some(log_message) if log_message.success => {
+                let message = log_message.message.to_owned();
+                self.execution()?.position += 1;
+                Ok(Some(message))
             }
-        };
+            _ => Ok(None),
+        }
+    }
 
+    /// Journals a newly generated message at the current position and advances past it.
+    ///
+    /// A failed message at the position is replaced along with every message after it, as those
+    /// were journaled by an execution that went on after the failure and are not replayed. The
+    /// signals those messages delivered are pending again, and the messages that started child
+    /// workflows are kept as detached, so the children are reattached rather than orphaned.
+    pub(crate) fn record(
+        &mut self,
+        request: &GuestToHost,
+        message: HostToGuest,
+    ) -> Result<HostToGuest> {
+        let journal = self.journal.clone();
+        let execution = self.execution()?;
+        let position = execution.position;
         execution.position += 1;
+        let execution_id = execution.id;
 
-        Ok(message)
+        if position < self.log.len() {
+            if let Some(journal) = &journal {
+                journal.truncate_log(&self.id, position)?;
+            }
+            for log_message in self.log.drain(position..).rev() {
+                match (&log_message.request, &log_message.message.content) {
+                    (GuestToHost::WaitForSignal(name), Content::Signal(payload)) => {
+                        self.signals.requeue(Signal {
+                            id: log_message.message.id.parse()?,
+                            created: log_message.created,
+                            name: name.to_owned(),
+                            payload: payload.to_owned(),
+                        });
+                    }
+                    (_, Content::Child(_)) => self.detached.push(log_message),
+                    _ => {}
+                }
+            }
+        }
+        let log_message = LogMessage::new(true, request.to_owned(), message);
+        if let Some(journal) = &journal {
+            journal.save_message(&execution_id, position, &log_message)?;
+        }
+        self.log.push(log_message.to_owned());
+
+        Ok(log_message.message)
     }
 
-    /// Marks all log messages with the given ID as failed in the last execution.
-    pub fn set_failure(&mut self, id: &str) {
-        if let Some(execution) = self.executions.last_mut() {
-            execution.log.iter_mut().for_each(|log_message| {
+    /// Marks all log messages with the given ID as failed, on behalf of the last execution.
+    pub fn set_failure(&mut self, id: &str) -> Result<()> {
+        if let Some(execution) = self.executions.last() {
+            for (position, log_message) in self.log.iter_mut().enumerate() {
                 if log_message.message.id == id {
                     log_message.success = false;
+                    if let Some(journal) = &self.journal {
+                        journal.save_message(&execution.id, position, log_message)?;
+                    }
                 }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Marks the host response the guest failed on as failed and converts the error.
+    ///
+    /// A failure caused by a request the network policy denied is raised as `Denied`, with the
+    /// error of the guest as its source.
+    pub(crate) fn failure(&mut self, err: WorkflowError) -> anyhow::Error {
+        let Some(id) = err.id.clone() else {
+            return err.into();
+        };
+        // a journal write failure must not mask the workflow error
+        _ = self.set_failure(&id);
+
+        let denied = self
+            .log
+            .iter()
+            .find_map(|log_message| match &log_message.message {
+                HostToGuest {
+                    id: message_id,
+                    content:
+                        Content::HttpResponse(Err(Error {
+                            url,
+                            kind: Some(Kind::Denied(reason)),
+                        })),
+                } if *message_id == id => Some(Denied {
+                    url: url.clone(),
+                    reason: reason.clone(),
+                }),
+                _ => None,
             });
+        match denied {
+            Some(denied) => anyhow::Error::new(err).context(denied),
+            None => err.into(),
         }
     }
 
     /// Returns a mutable reference to the current execution in the state.
-    fn execution(&mut self) -> &mut Execution {
-        self.executions.last_mut().unwrap()
+    fn execution(&mut self) -> Result<&mut Execution> {
+        self.executions
+            .last_mut()
+            .ok_or_else(|| anyhow!("workflow {} has no running execution", self.id))
     }
 }